@@ -0,0 +1,37 @@
+//! Print shell completions that suggest level names for `--log-level`
+//!
+//! Run with `cargo run --example completions -- bash` (or `zsh`).  To instead generate them when
+//! building, put the same `clap_complete::generate_to` call in a `build.rs`:
+//!
+//! ```rust,ignore
+//! let out_dir = std::env::var_os("OUT_DIR").unwrap();
+//! for shell in [Shell::Bash, Shell::Zsh] {
+//!     clap_complete::generate_to(shell, &mut Cli::command(), "completions", &out_dir)?;
+//! }
+//! ```
+
+use clap::{CommandFactory, Parser};
+use clap_complete::Shell;
+use clap_verbosity_flag::LogLevelArg;
+
+/// Foo
+#[derive(Debug, Parser)]
+struct Cli {
+    /// Shell to generate completions for
+    #[arg(value_enum)]
+    shell: Shell,
+
+    #[command(flatten)]
+    verbose: LogLevelArg,
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    clap_complete::generate(
+        cli.shell,
+        &mut Cli::command(),
+        "completions",
+        &mut std::io::stdout(),
+    );
+}
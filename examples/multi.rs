@@ -0,0 +1,62 @@
+use clap::{CommandFactory, FromArgMatches, Parser};
+use clap_verbosity_flag::{MultiVerbosity, WarnLevel};
+
+/// A compiler with `--parser-verbose`, `--codegen-quiet`, etc.
+#[derive(Debug, Parser)]
+struct Cli {
+    #[command(flatten)]
+    verbose: MultiVerbosity<WarnLevel>,
+}
+
+const SUBSYSTEMS: [&str; 3] = ["parser", "codegen", "linker"];
+
+fn main() {
+    let cmd = SUBSYSTEMS.iter().fold(Cli::command(), |cmd, name| {
+        MultiVerbosity::<WarnLevel>::register(cmd, name)
+    });
+    let matches = cmd.get_matches();
+    let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|err| err.exit());
+
+    let max = SUBSYSTEMS
+        .iter()
+        .map(|name| cli.verbose.get_or_default(name).log_level_filter())
+        .max()
+        .unwrap_or(log::LevelFilter::Off);
+    let mut builder = pretty_env_logger::formatted_builder();
+    builder.filter_level(max);
+    for name in SUBSYSTEMS {
+        builder.filter_module(
+            &format!("multi::{name}"),
+            cli.verbose.get_or_default(name).log_level_filter(),
+        );
+    }
+    builder.init();
+
+    parser::run();
+    codegen::run();
+    linker::run();
+}
+
+mod parser {
+    pub(crate) fn run() {
+        log::warn!("Unused import");
+        log::info!("Parsed 3 files");
+        log::debug!("Token count is 1200");
+    }
+}
+
+mod codegen {
+    pub(crate) fn run() {
+        log::warn!("Function is too large to inline");
+        log::info!("Generated 40 functions");
+        log::debug!("Register pressure is high");
+    }
+}
+
+mod linker {
+    pub(crate) fn run() {
+        log::warn!("Duplicate symbol ignored");
+        log::info!("Linked 2 objects");
+        log::debug!("Output is 2 MB");
+    }
+}
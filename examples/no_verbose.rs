@@ -0,0 +1,23 @@
+use clap::Parser;
+use clap_verbosity_flag::{TraceLevel, VerbosityNoVerbose};
+
+/// Foo
+#[derive(Debug, Parser)]
+struct Cli {
+    #[command(flatten)]
+    verbose: VerbosityNoVerbose<TraceLevel>,
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    pretty_env_logger::formatted_builder()
+        .filter_level(cli.verbose.log_level_filter())
+        .init();
+
+    log::error!("Engines exploded");
+    log::warn!("Engines smoking");
+    log::info!("Engines exist");
+    log::debug!("Engine temperature is 200 degrees");
+    log::trace!("Engine subsection is 300 degrees");
+}
@@ -0,0 +1,21 @@
+use clap::Parser;
+use clap_verbosity_flag::Verbosity;
+use opentelemetry::logs::AnyValue;
+
+/// Foo
+#[derive(Debug, Parser)]
+struct Cli {
+    #[command(flatten)]
+    verbose: Verbosity,
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    println!("severity: {}", cli.verbose.otel_severity_text());
+
+    // Hand this to a `opentelemetry::logs::Logger::emit`
+    let mut record = cli.verbose.to_otel_log_record();
+    record.body = Some(AnyValue::from("Engines exist"));
+    println!("{record:?}");
+}
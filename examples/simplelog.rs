@@ -0,0 +1,23 @@
+use clap::Parser;
+use clap_verbosity_flag::Verbosity;
+
+/// Foo
+#[derive(Debug, Parser)]
+struct Cli {
+    #[command(flatten)]
+    verbose: Verbosity,
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    cli.verbose
+        .init_termlog(simplelog::TerminalMode::Stderr)
+        .unwrap();
+
+    log::error!("Engines exploded");
+    log::warn!("Engines smoking");
+    log::info!("Engines exist");
+    log::debug!("Engine temperature is 200 degrees");
+    log::trace!("Engine subsection is 300 degrees");
+}
@@ -0,0 +1,36 @@
+use clap::Parser;
+use clap_verbosity_flag::Verbosity;
+use slog::Drain;
+
+/// Foo
+#[derive(Debug, Parser)]
+struct Cli {
+    #[command(flatten)]
+    verbose: Verbosity,
+}
+
+/// Minimal drain; use `slog-term` or `slog-json` in real programs
+struct Stderr;
+
+impl Drain for Stderr {
+    type Ok = ();
+    type Err = slog::Never;
+
+    fn log(&self, record: &slog::Record<'_>, _: &slog::OwnedKVList) -> Result<(), slog::Never> {
+        eprintln!("{} {}", record.level().as_short_str(), record.msg());
+        Ok(())
+    }
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    let root = slog::Logger::root(Stderr.fuse(), slog::o!());
+    let logger = cli.verbose.apply_to_slog_logger(&root);
+
+    slog::error!(logger, "Engines exploded");
+    slog::warn!(logger, "Engines smoking");
+    slog::info!(logger, "Engines exist");
+    slog::debug!(logger, "Engine temperature is 200 degrees");
+    slog::trace!(logger, "Engine subsection is 300 degrees");
+}
@@ -1,6 +1,5 @@
 use clap::Parser;
 use clap_verbosity_flag::Verbosity;
-use tracing_subscriber::util::SubscriberInitExt;
 
 /// Foo
 #[derive(Debug, Parser)]
@@ -0,0 +1,48 @@
+use std::sync::mpsc;
+use std::thread;
+
+use clap::Parser;
+use clap_verbosity_flag::{Verbosity, VerbosityLayer};
+use tracing_subscriber::layer::SubscriberExt as _;
+use tracing_subscriber::util::SubscriberInitExt as _;
+
+/// Foo
+#[derive(Debug, Parser)]
+struct Cli {
+    #[command(flatten)]
+    verbose: Verbosity,
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    let (layer, handle) = VerbosityLayer::new(cli.verbose);
+    tracing_subscriber::registry()
+        .with(layer)
+        .with(tracing_subscriber::fmt::layer())
+        .init();
+
+    // Stand-in for a signal handler or admin command asking for more output
+    let (sender, receiver) = mpsc::channel();
+    let control = thread::spawn(move || {
+        for _ in 0..4 {
+            sender.send(()).unwrap();
+        }
+    });
+
+    report();
+    for () in receiver {
+        handle.write().unwrap().increment();
+        eprintln!("-- now at {}", handle.read().unwrap());
+        report();
+    }
+    control.join().unwrap();
+}
+
+fn report() {
+    tracing::error!("Engines exploded");
+    tracing::warn!("Engines smoking");
+    tracing::info!("Engines exist");
+    tracing::debug!("Engine temperature is 200 degrees");
+    tracing::trace!("Engine subsection is 300 degrees");
+}
@@ -0,0 +1,21 @@
+#![no_main]
+
+use clap_verbosity_flag::{ErrorLevel, InfoLevel, NoneLevel, TraceLevel, Verbosity};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: (
+    Verbosity<NoneLevel>,
+    Verbosity<ErrorLevel>,
+    Verbosity<InfoLevel>,
+    Verbosity<TraceLevel>
+)| {
+    let (none, error, info, trace) = input;
+    let _ = none.log_level();
+    let _ = none.tracing_level_filter();
+    let _ = error.log_level();
+    let _ = error.tracing_level_filter();
+    let _ = info.log_level();
+    let _ = info.tracing_level_filter();
+    let _ = trace.log_level();
+    let _ = trace.tracing_level_filter();
+});
@@ -0,0 +1,146 @@
+use crate::ErrorLevel;
+use crate::LogLevel;
+use crate::Verbosity;
+
+/// [`Verbosity`] labeled with where it came from, see [`Verbosity::with_context`]
+///
+/// Combining annotated verbosities, with [`AnnotatedVerbosity::merge`] or `|`, joins their
+/// sources with ` + `, so [`AnnotatedVerbosity::to_diagnostic`] can show every contributor.
+///
+/// ```rust
+/// use clap_verbosity_flag::{InfoLevel, Verbosity};
+///
+/// let flags = Verbosity::<InfoLevel>::new(1, 0).with_context("from CLI flags");
+/// let config = Verbosity::<InfoLevel>::new(0, 1).with_context("from config file");
+/// let verbosity = flags | config;
+/// assert_eq!(verbosity.source(), "from CLI flags + from config file");
+/// assert_eq!(verbosity.level_name(), "debug");
+/// ```
+#[derive(Debug, Clone)]
+pub struct AnnotatedVerbosity<L: LogLevel = ErrorLevel> {
+    inner: Verbosity<L>,
+    source: String,
+}
+
+impl<L: LogLevel> AnnotatedVerbosity<L> {
+    /// Label `verbosity` with its `source`
+    pub fn new(verbosity: Verbosity<L>, source: impl Into<String>) -> Self {
+        AnnotatedVerbosity {
+            inner: verbosity,
+            source: source.into(),
+        }
+    }
+
+    /// Where the verbosity came from, like `from CLI flags`
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Combine with `other`, keeping the more verbose of the two, like [`Verbosity::merge`]
+    pub fn merge(&self, other: &AnnotatedVerbosity<L>) -> AnnotatedVerbosity<L> {
+        AnnotatedVerbosity {
+            inner: self.inner.merge(&other.inner),
+            source: join_sources(&self.source, &other.source),
+        }
+    }
+
+    /// Like [`Verbosity::to_diagnostic`], followed by the source
+    pub fn to_diagnostic(&self) -> String {
+        format!("{} source={:?}", self.inner.to_diagnostic(), self.source)
+    }
+
+    /// Unwrap the underlying [`Verbosity`]
+    pub fn into_inner(self) -> Verbosity<L> {
+        self.inner
+    }
+}
+
+impl<L: LogLevel> Verbosity<L> {
+    /// Label this verbosity with where it came from, like `from config file`
+    pub fn with_context(&self, label: impl Into<String>) -> AnnotatedVerbosity<L> {
+        AnnotatedVerbosity::new(
+            Verbosity::new(self.verbose_count(), self.quiet_count()),
+            label,
+        )
+    }
+}
+
+fn join_sources(first: &str, second: &str) -> String {
+    format!("{first} + {second}")
+}
+
+impl<L: LogLevel> std::ops::Deref for AnnotatedVerbosity<L> {
+    type Target = Verbosity<L>;
+
+    fn deref(&self) -> &Verbosity<L> {
+        &self.inner
+    }
+}
+
+impl<L: LogLevel> From<AnnotatedVerbosity<L>> for Verbosity<L> {
+    fn from(verbosity: AnnotatedVerbosity<L>) -> Self {
+        verbosity.inner
+    }
+}
+
+/// Take the more verbose of the two, like [`Verbosity`]'s `|`, joining the sources
+impl<L: LogLevel> std::ops::BitOr for AnnotatedVerbosity<L> {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        AnnotatedVerbosity {
+            source: join_sources(&self.source, &rhs.source),
+            inner: self.inner | rhs.inner,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::InfoLevel;
+
+    #[test]
+    fn source() {
+        let verbosity = Verbosity::<InfoLevel>::new(1, 0).with_context("from CLI flags");
+        assert_eq!(verbosity.source(), "from CLI flags");
+        assert_eq!(verbosity.level_name(), "debug");
+        assert_eq!(verbosity.into_inner().verbose_count(), 1);
+
+        let owned = Verbosity::<InfoLevel>::new(0, 0).with_context(String::from("from tests"));
+        assert_eq!(owned.source(), "from tests");
+    }
+
+    #[test]
+    fn combine_three() {
+        let flags = Verbosity::<InfoLevel>::new(0, 1).with_context("from CLI flags");
+        let config = Verbosity::<InfoLevel>::new(2, 0).with_context("from config file");
+        let env = Verbosity::<InfoLevel>::new(1, 0).with_context("from environment");
+
+        let merged = flags.merge(&config).merge(&env);
+        assert_eq!(
+            merged.source(),
+            "from CLI flags + from config file + from environment"
+        );
+        assert_eq!(merged.level_name(), "trace");
+
+        let combined = flags | config | env;
+        assert_eq!(
+            combined.source(),
+            "from CLI flags + from config file + from environment"
+        );
+        assert_eq!(combined.level_name(), "trace");
+    }
+
+    #[test]
+    fn to_diagnostic() {
+        let verbosity = Verbosity::<InfoLevel>::new(0, 1).with_context("from config file")
+            | Verbosity::new(0, 0).with_context("from CLI flags");
+        let diagnostic = verbosity.to_diagnostic();
+        assert!(diagnostic.starts_with("verbose=0 quiet=0 default=info effective=info "));
+        assert!(
+            diagnostic.ends_with(r#" source="from config file + from CLI flags""#),
+            "{diagnostic}"
+        );
+    }
+}
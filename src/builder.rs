@@ -0,0 +1,252 @@
+use std::fmt;
+
+use crate::LogLevel;
+use crate::Verbosity;
+
+/// Programmatically construct a [`Verbosity`], see [`Verbosity::builder`]
+///
+/// ```rust
+/// use clap_verbosity_flag::{InfoLevel, Verbosity};
+///
+/// # #[cfg(feature = "log")]
+/// # fn main() {
+/// let verbosity = Verbosity::<InfoLevel>::builder()
+///     .verbose(3)
+///     .max_level(log::Level::Debug)
+///     .build()
+///     .unwrap();
+/// assert_eq!(verbosity.log_level(), Some(log::Level::Debug));
+/// # }
+/// # #[cfg(not(feature = "log"))]
+/// # fn main() {}
+/// ```
+#[derive(Debug)]
+pub struct VerbosityBuilder<L: LogLevel> {
+    verbose: u8,
+    quiet: u8,
+    #[cfg(feature = "log")]
+    max_level: Option<log::Level>,
+    permissive: bool,
+    phantom: std::marker::PhantomData<L>,
+}
+
+impl<L: LogLevel> VerbosityBuilder<L> {
+    fn new() -> Self {
+        VerbosityBuilder {
+            verbose: 0,
+            quiet: 0,
+            #[cfg(feature = "log")]
+            max_level: None,
+            permissive: false,
+            phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Number of times `--verbose` was passed
+    pub fn verbose(mut self, verbose: u8) -> Self {
+        self.verbose = verbose;
+        self
+    }
+
+    /// Number of times `--quiet` was passed
+    pub fn quiet(mut self, quiet: u8) -> Self {
+        self.quiet = quiet;
+        self
+    }
+
+    /// Clear the `--quiet` count
+    pub fn no_quiet(self) -> Self {
+        self.quiet(0)
+    }
+
+    /// Adjust the counts so the level is no more verbose than `level`
+    ///
+    /// When `level` is quieter than `L`'s default, this sets the `--quiet` count.
+    #[cfg(feature = "log")]
+    pub fn max_level(mut self, level: log::Level) -> Self {
+        self.max_level = Some(level);
+        self
+    }
+
+    /// Allow both `--verbose` and `--quiet`, like [`VerbosityPermissive`][crate::VerbosityPermissive]
+    pub fn permissive(mut self) -> Self {
+        self.permissive = true;
+        self
+    }
+
+    /// Create the [`Verbosity`]
+    ///
+    /// # Errors
+    ///
+    /// When both `verbose` and `quiet` are set, unless [`VerbosityBuilder::permissive`] or
+    /// [`LogLevel::verbose_conflicts_with_quiet`] is `false`
+    pub fn build(self) -> Result<Verbosity<L>, VerbosityError> {
+        if !self.permissive
+            && L::verbose_conflicts_with_quiet()
+            && self.verbose != 0
+            && self.quiet != 0
+        {
+            return Err(VerbosityError::Conflict {
+                verbose: self.verbose,
+                quiet: self.quiet,
+            });
+        }
+
+        Ok(self.capped(Verbosity::new(self.verbose, self.quiet)))
+    }
+
+    #[cfg(feature = "log")]
+    fn capped(&self, verbosity: Verbosity<L>) -> Verbosity<L> {
+        let max = crate::level_value_log(self.max_level);
+        if self.max_level.is_none() || verbosity.as_i8() <= max {
+            return verbosity;
+        }
+        // Round towards quieter, so a step size above `1` can't overshoot `max`
+        let offset = max as i16 - Verbosity::<L>::default_verbosity() as i16;
+        let net = offset.div_euclid(Verbosity::<L>::step_size() as i16);
+        let count = net.unsigned_abs().min(u8::MAX as u16) as u8;
+        if net < 0 {
+            Verbosity::new(0, count)
+        } else {
+            Verbosity::new(count, 0)
+        }
+    }
+
+    #[cfg(not(feature = "log"))]
+    fn capped(&self, verbosity: Verbosity<L>) -> Verbosity<L> {
+        verbosity
+    }
+}
+
+impl<L: LogLevel> Verbosity<L> {
+    /// Programmatically construct a verbosity instance
+    pub fn builder() -> VerbosityBuilder<L> {
+        VerbosityBuilder::new()
+    }
+}
+
+/// A [`VerbosityBuilder`] had an invalid combination of settings
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum VerbosityError {
+    /// `--verbose` and `--quiet` were both set
+    Conflict { verbose: u8, quiet: u8 },
+}
+
+impl fmt::Display for VerbosityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VerbosityError::Conflict { verbose, quiet } => write!(
+                f,
+                "`verbose` ({verbose}) and `quiet` ({quiet}) cannot both be set"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for VerbosityError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ErrorLevel;
+    use crate::InfoLevel;
+
+    #[test]
+    fn defaults() {
+        let verbosity = Verbosity::<InfoLevel>::builder().build().unwrap();
+        assert_eq!(verbosity.verbose_count(), 0);
+        assert_eq!(verbosity.quiet_count(), 0);
+    }
+
+    #[test]
+    fn chaining() {
+        let verbosity = Verbosity::<ErrorLevel>::builder()
+            .verbose(1)
+            .verbose(2)
+            .build()
+            .unwrap();
+        assert_eq!(verbosity.verbose_count(), 2);
+
+        let verbosity = Verbosity::<ErrorLevel>::builder()
+            .quiet(3)
+            .no_quiet()
+            .verbose(1)
+            .build()
+            .unwrap();
+        assert_eq!(verbosity.verbose_count(), 1);
+        assert_eq!(verbosity.quiet_count(), 0);
+    }
+
+    #[test]
+    fn conflict() {
+        let err = Verbosity::<ErrorLevel>::builder()
+            .verbose(2)
+            .quiet(1)
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            VerbosityError::Conflict {
+                verbose: 2,
+                quiet: 1
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "`verbose` (2) and `quiet` (1) cannot both be set"
+        );
+
+        let verbosity = Verbosity::<ErrorLevel>::builder()
+            .verbose(2)
+            .quiet(1)
+            .permissive()
+            .build()
+            .unwrap();
+        assert_eq!(verbosity.as_i8(), 1);
+    }
+
+    #[test]
+    #[cfg(feature = "log")]
+    fn max_level() {
+        let verbosity = Verbosity::<InfoLevel>::builder()
+            .verbose(5)
+            .max_level(log::Level::Debug)
+            .build()
+            .unwrap();
+        assert_eq!(verbosity.verbose_count(), 1);
+        assert_eq!(verbosity.log_level(), Some(log::Level::Debug));
+
+        let verbosity = Verbosity::<InfoLevel>::builder()
+            .max_level(log::Level::Trace)
+            .quiet(1)
+            .build()
+            .unwrap();
+        assert_eq!(verbosity.log_level(), Some(log::Level::Warn));
+
+        // Below the default, the shortfall becomes `--quiet`
+        let verbosity = Verbosity::<InfoLevel>::builder()
+            .max_level(log::Level::Error)
+            .build()
+            .unwrap();
+        assert_eq!(verbosity.log_level(), Some(log::Level::Error));
+        assert_eq!(verbosity.verbose_count(), 0);
+        assert_eq!(verbosity.quiet_count(), 2);
+
+        let verbosity = Verbosity::<InfoLevel>::builder()
+            .verbose(3)
+            .max_level(log::Level::Warn)
+            .build()
+            .unwrap();
+        assert_eq!(verbosity.log_level(), Some(log::Level::Warn));
+
+        // Already quieter than the maximum, so left alone
+        let verbosity = Verbosity::<InfoLevel>::builder()
+            .quiet(2)
+            .max_level(log::Level::Warn)
+            .build()
+            .unwrap();
+        assert_eq!(verbosity.log_level(), Some(log::Level::Error));
+        assert_eq!(verbosity.quiet_count(), 2);
+    }
+}
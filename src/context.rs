@@ -0,0 +1,100 @@
+use std::fmt;
+
+use crate::LogLevel;
+use crate::Verbosity;
+
+/// How a [`Verbosity`] was resolved, see [`Verbosity::context`]
+///
+/// This is the structured form of [`Verbosity::to_diagnostic`].  [`Display`][fmt::Display] shows
+/// one field per line.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct VerbosityContext {
+    /// Number of times `--verbose` was passed
+    pub verbose: u8,
+    /// Number of times `--quiet` was passed
+    pub quiet: u8,
+    /// Name of the level without any flags, see [`LogLevel::label`]
+    pub default_level: &'static str,
+    /// Name of the resulting level, see [`Verbosity::level_name`]
+    pub effective_level: &'static str,
+    /// See [`Verbosity::is_silent`]
+    pub is_silent: bool,
+}
+
+impl<L: LogLevel> Verbosity<L> {
+    /// Describe how the level was resolved, for debugging configuration problems
+    pub fn context(&self) -> VerbosityContext {
+        VerbosityContext {
+            verbose: self.verbose_count(),
+            quiet: self.quiet_count(),
+            default_level: L::label(),
+            effective_level: self.level_name(),
+            is_silent: self.is_silent(),
+        }
+    }
+}
+
+impl fmt::Display for VerbosityContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "verbose: {}", self.verbose)?;
+        writeln!(f, "quiet: {}", self.quiet)?;
+        writeln!(f, "default level: {}", self.default_level)?;
+        writeln!(f, "effective level: {}", self.effective_level)?;
+        write!(f, "silent: {}", self.is_silent)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ErrorLevel;
+    use crate::InfoLevel;
+
+    #[test]
+    fn fields() {
+        let context = Verbosity::<InfoLevel>::new(1, 0).context();
+        assert_eq!(
+            context,
+            VerbosityContext {
+                verbose: 1,
+                quiet: 0,
+                default_level: "info",
+                effective_level: "debug",
+                is_silent: false,
+            }
+        );
+
+        let context = Verbosity::<ErrorLevel>::new(0, 1).context();
+        assert_eq!(context.default_level, "error");
+        assert_eq!(context.effective_level, "off");
+        assert!(context.is_silent);
+
+        for (verbose, quiet) in [(0, 0), (3, 0), (0, 2), (9, 0)] {
+            let verbosity = Verbosity::<InfoLevel>::new(verbose, quiet);
+            let context = verbosity.context();
+            assert_eq!(context.verbose, verbose);
+            assert_eq!(context.quiet, quiet);
+            assert_eq!(context.effective_level, verbosity.level_name());
+        }
+    }
+
+    #[test]
+    fn display() {
+        let context = Verbosity::<ErrorLevel>::new(2, 0).context();
+        assert_eq!(
+            context.to_string(),
+            "verbose: 2\nquiet: 0\ndefault level: error\neffective level: info\nsilent: false"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serialize() {
+        let context = Verbosity::<ErrorLevel>::new(0, 0).context();
+        assert_eq!(
+            serde_json::to_string(&context).unwrap(),
+            r#"{"verbose":0,"quiet":0,"default_level":"error","effective_level":"error","is_silent":false}"#
+        );
+    }
+}
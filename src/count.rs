@@ -0,0 +1,208 @@
+use crate::ErrorLevel;
+use crate::LogLevel;
+use crate::Verbosity;
+
+/// [`Verbosity`] that also accepts an explicit count, like `--verbose=3`
+///
+/// `-vvv`, `--verbose --verbose --verbose`, and `--verbose=3` are all equivalent.  Counts above
+/// `255` are clamped.
+///
+/// ```rust,no_run
+/// # use clap::Parser;
+/// use clap_verbosity_flag::VerbosityCount;
+///
+/// /// Le CLI
+/// #[derive(Debug, Parser)]
+/// struct Cli {
+///     #[command(flatten)]
+///     verbose: VerbosityCount,
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct VerbosityCount<L: LogLevel = ErrorLevel> {
+    inner: Verbosity<L>,
+}
+
+impl<L: LogLevel> VerbosityCount<L> {
+    /// Create a new verbosity instance by explicitly setting the values
+    pub fn new(verbose: u8, quiet: u8) -> Self {
+        VerbosityCount {
+            inner: Verbosity::new(verbose, quiet),
+        }
+    }
+
+    /// Unwrap the underlying [`Verbosity`]
+    pub fn into_inner(self) -> Verbosity<L> {
+        self.inner
+    }
+}
+
+impl<L: LogLevel> std::ops::Deref for VerbosityCount<L> {
+    type Target = Verbosity<L>;
+
+    fn deref(&self) -> &Verbosity<L> {
+        &self.inner
+    }
+}
+
+impl<L: LogLevel> From<VerbosityCount<L>> for Verbosity<L> {
+    fn from(verbosity: VerbosityCount<L>) -> Self {
+        verbosity.inner
+    }
+}
+
+impl<L: LogLevel> clap::FromArgMatches for VerbosityCount<L> {
+    fn from_arg_matches(matches: &clap::ArgMatches) -> Result<Self, clap::Error> {
+        let mut verbosity = Self::new(0, 0);
+        verbosity.update_from_arg_matches(matches)?;
+        Ok(verbosity)
+    }
+
+    fn update_from_arg_matches(&mut self, matches: &clap::ArgMatches) -> Result<(), clap::Error> {
+        // Not defined when `LogLevel::verbose_short_char` is `None`
+        let short = matches
+            .try_get_one::<u8>(crate::VERBOSE)
+            .ok()
+            .flatten()
+            .copied()
+            .unwrap_or(0);
+        let verbose = matches
+            .get_many::<u8>(VERBOSE_VALUE)
+            .into_iter()
+            .flatten()
+            .fold(short, |sum, count| sum.saturating_add(*count));
+        let quiet = matches.get_count(crate::QUIET);
+        self.inner = Verbosity::new(verbose, quiet).with_level_env();
+        Ok(())
+    }
+}
+
+impl<L: LogLevel> clap::Args for VerbosityCount<L> {
+    fn augment_args(cmd: clap::Command) -> clap::Command {
+        let mut quiet = crate::quiet_arg::<L>();
+        let cmd = if L::verbose_short_char().is_some() {
+            cmd.arg(
+                clap::Arg::new(crate::VERBOSE)
+                    .short(L::verbose_short_char())
+                    .action(clap::ArgAction::Count)
+                    .global(true)
+                    .help(L::verbose_help())
+                    .long_help(L::verbose_long_help()),
+            )
+        } else {
+            quiet = quiet.conflicts_with(clap::builder::Resettable::Reset);
+            cmd
+        };
+        cmd.arg(
+            clap::Arg::new(VERBOSE_VALUE)
+                .long(L::verbose_long_name())
+                .visible_aliases(L::verbose_aliases().iter().copied())
+                .value_name("COUNT")
+                .value_parser(parse_count)
+                .num_args(0..=1)
+                .require_equals(true)
+                .default_missing_value("1")
+                .action(clap::ArgAction::Append)
+                .global(true)
+                .help(L::verbose_help())
+                .long_help(L::verbose_long_help()),
+        )
+        .arg(if L::verbose_conflicts_with_quiet() {
+            quiet.conflicts_with(VERBOSE_VALUE)
+        } else {
+            quiet
+        })
+    }
+
+    fn augment_args_for_update(cmd: clap::Command) -> clap::Command {
+        Self::augment_args(cmd)
+    }
+}
+
+const VERBOSE_VALUE: &str = "verbose_count";
+
+fn parse_count(value: &str) -> Result<u8, String> {
+    let count = value
+        .parse::<u64>()
+        .map_err(|_| format!("`{value}` is not a non-negative integer"))?;
+    Ok(u8::try_from(count).unwrap_or(u8::MAX))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Debug, Parser)]
+    struct Cli {
+        #[command(flatten)]
+        verbose: VerbosityCount,
+    }
+
+    #[test]
+    fn verify_app() {
+        use clap::CommandFactory;
+        Cli::command().debug_assert()
+    }
+
+    #[test]
+    #[cfg(feature = "log")]
+    fn count_and_value_forms_match() {
+        for (count, value) in [
+            (&["cli"][..], &["cli"][..]),
+            (&["cli", "-v"], &["cli", "--verbose=1"]),
+            (&["cli", "-vvv"], &["cli", "--verbose=3"]),
+            (&["cli", "--verbose", "--verbose"], &["cli", "--verbose=2"]),
+            (&["cli", "-v", "--verbose"], &["cli", "--verbose=2"]),
+        ] {
+            let count = Cli::try_parse_from(count).unwrap();
+            let value = Cli::try_parse_from(value).unwrap();
+            assert_eq!(count.verbose.log_level(), value.verbose.log_level());
+            assert_eq!(count.verbose.verbose_count(), value.verbose.verbose_count());
+        }
+    }
+
+    #[test]
+    fn value_is_clamped() {
+        let cli = Cli::try_parse_from(["cli", "--verbose=1000"]).unwrap();
+        assert_eq!(cli.verbose.verbose_count(), u8::MAX);
+
+        assert!(Cli::try_parse_from(["cli", "--verbose=-1"]).is_err());
+        assert!(Cli::try_parse_from(["cli", "--verbose=3", "-q"]).is_err());
+    }
+
+    #[test]
+    fn without_short() {
+        #[derive(Copy, Clone, Debug, Default)]
+        struct LongOnly;
+
+        impl LogLevel for LongOnly {
+            #[cfg(feature = "log")]
+            fn default_log() -> Option<log::Level> {
+                Some(log::Level::Error)
+            }
+
+            #[cfg(feature = "tracing")]
+            fn default_tracing() -> Option<tracing_subscriber::filter::LevelFilter> {
+                Some(tracing_subscriber::filter::LevelFilter::ERROR)
+            }
+
+            fn verbose_short_char() -> Option<char> {
+                None
+            }
+        }
+
+        #[derive(Debug, Parser)]
+        struct Cli {
+            #[command(flatten)]
+            verbose: VerbosityCount<LongOnly>,
+        }
+
+        use clap::CommandFactory;
+        Cli::command().debug_assert();
+
+        let cli = Cli::parse_from(["cli", "--verbose=2", "--verbose"]);
+        assert_eq!(cli.verbose.verbose_count(), 3);
+        assert!(Cli::try_parse_from(["cli", "-v"]).is_err());
+    }
+}
@@ -0,0 +1,119 @@
+use std::cell::Cell;
+
+use crate::LogLevel;
+use crate::Verbosity;
+
+thread_local! {
+    static DEFAULT: Cell<Option<log::Level>> = const { Cell::new(Some(log::Level::Error)) };
+}
+
+/// [`LogLevel`] whose default is picked at runtime, like from a config file
+///
+/// The default is per-thread, set by [`Verbosity::with_runtime_default`], and is read whenever
+/// the level is resolved.  Until one is set, it is the same as [`ErrorLevel`][crate::ErrorLevel].
+///
+/// ```rust
+/// use clap_verbosity_flag::{DynamicLevel, Verbosity};
+///
+/// let flags = Verbosity::<DynamicLevel>::new(1, 0);
+/// Verbosity::with_runtime_default(Some(log::Level::Info));
+/// assert_eq!(flags.log_level(), Some(log::Level::Debug));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DynamicLevel(pub Option<log::Level>);
+
+impl DynamicLevel {
+    /// The default currently set on this thread
+    pub fn current() -> Self {
+        DynamicLevel(DEFAULT.with(Cell::get))
+    }
+}
+
+impl LogLevel for DynamicLevel {
+    fn default_log() -> Option<log::Level> {
+        Self::current().0
+    }
+
+    #[cfg(feature = "tracing")]
+    fn default_tracing() -> Option<tracing_subscriber::filter::LevelFilter> {
+        Some(crate::level_enum_tracing(crate::level_value_log(
+            Self::default_log(),
+        )))
+    }
+}
+
+impl Verbosity<DynamicLevel> {
+    /// Create a verbosity whose default is `base`, instead of a type-level default
+    ///
+    /// `base` becomes the default of every `Verbosity<DynamicLevel>` on the current thread,
+    /// including ones parsed earlier, while other threads keep their own default.
+    pub fn with_runtime_default(base: Option<log::Level>) -> Self {
+        DEFAULT.with(|default| default.set(base));
+        Verbosity::new(0, 0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn defaults_to_error() {
+        assert_eq!(
+            DynamicLevel::current(),
+            DynamicLevel(Some(log::Level::Error))
+        );
+        let verbosity = Verbosity::<DynamicLevel>::new(0, 0);
+        assert_eq!(verbosity.log_level(), Some(log::Level::Error));
+    }
+
+    #[test]
+    fn runtime_defaults() {
+        let flags = Verbosity::<DynamicLevel>::new(1, 0);
+        for (base, expected) in [
+            (None, Some(log::Level::Error)),
+            (Some(log::Level::Error), Some(log::Level::Warn)),
+            (Some(log::Level::Info), Some(log::Level::Debug)),
+            (Some(log::Level::Trace), Some(log::Level::Trace)),
+        ] {
+            let default = Verbosity::with_runtime_default(base);
+            assert_eq!(default.log_level(), base, "{base:?}");
+            assert_eq!(DynamicLevel::current(), DynamicLevel(base));
+            assert_eq!(flags.log_level(), expected, "{base:?}");
+        }
+
+        let quiet = Verbosity::<DynamicLevel>::new(0, 1);
+        Verbosity::with_runtime_default(Some(log::Level::Info));
+        assert_eq!(quiet.log_level(), Some(log::Level::Warn));
+        Verbosity::with_runtime_default(Some(log::Level::Debug));
+        assert_eq!(quiet.log_level(), Some(log::Level::Info));
+    }
+
+    #[test]
+    fn per_thread() {
+        Verbosity::with_runtime_default(Some(log::Level::Info));
+        std::thread::spawn(|| {
+            assert_eq!(
+                DynamicLevel::current(),
+                DynamicLevel(Some(log::Level::Error))
+            )
+        })
+        .join()
+        .unwrap();
+        assert_eq!(
+            DynamicLevel::current(),
+            DynamicLevel(Some(log::Level::Info))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "tracing")]
+    fn tracing_default() {
+        use tracing_subscriber::filter::LevelFilter;
+
+        Verbosity::with_runtime_default(Some(log::Level::Debug));
+        assert_eq!(DynamicLevel::default_tracing(), Some(LevelFilter::DEBUG));
+        Verbosity::with_runtime_default(None);
+        assert_eq!(DynamicLevel::default_tracing(), Some(LevelFilter::OFF));
+    }
+}
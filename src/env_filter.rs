@@ -0,0 +1,131 @@
+use tracing_subscriber::filter::Directive;
+use tracing_subscriber::filter::EnvFilter;
+use tracing_subscriber::filter::ParseError;
+
+use crate::LogLevel;
+use crate::Verbosity;
+
+impl<L: LogLevel> Verbosity<L> {
+    /// Create an [`EnvFilter`] with [`Verbosity::tracing_level_filter`] as the global level
+    ///
+    /// Unlike a plain [`LevelFilter`][tracing_subscriber::filter::LevelFilter], directives for
+    /// specific modules can be added to the result.
+    pub fn to_env_filter(&self) -> EnvFilter {
+        self.merge_into_env_filter(EnvFilter::default())
+    }
+
+    /// Use [`Verbosity::tracing_level_filter`] for everything `existing` doesn't have a directive
+    /// for
+    ///
+    /// ```rust
+    /// use clap_verbosity_flag::{InfoLevel, Verbosity};
+    /// use tracing_subscriber::filter::EnvFilter;
+    ///
+    /// let verbosity = Verbosity::<InfoLevel>::new(0, 0);
+    /// let filter = verbosity.merge_into_env_filter(EnvFilter::new("my_crate=trace"));
+    /// ```
+    pub fn merge_into_env_filter(&self, existing: EnvFilter) -> EnvFilter {
+        existing.add_directive(self.tracing_level_filter().into())
+    }
+
+    /// Combine with a `RUST_LOG`-style `base_filter`, like from `std::env::var("RUST_LOG")`
+    ///
+    /// [`Verbosity::tracing_level_filter`] becomes the global level, while per-module directives
+    /// in `base_filter` still apply to their modules.  Invalid directives are ignored.
+    ///
+    /// ```rust
+    /// use clap_verbosity_flag::{InfoLevel, Verbosity};
+    ///
+    /// let base_filter = std::env::var("RUST_LOG").unwrap_or_default();
+    /// let filter = Verbosity::<InfoLevel>::new(0, 0).combines_env_filter_with(&base_filter);
+    /// ```
+    pub fn combines_env_filter_with(&self, base_filter: &str) -> EnvFilter {
+        self.merge_into_env_filter(EnvFilter::new(base_filter))
+    }
+
+    /// Parse [`Verbosity::tracing_level_directive`] into a [`Directive`]
+    pub fn parse_tracing_directive(&self) -> Result<Directive, ParseError> {
+        self.tracing_level_directive().parse()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use tracing_subscriber::filter::LevelFilter;
+    use tracing_subscriber::layer::SubscriberExt as _;
+
+    use super::*;
+    use crate::InfoLevel;
+
+    fn enabled(filter: EnvFilter) -> [bool; 5] {
+        let subscriber = tracing_subscriber::registry().with(filter);
+        tracing::subscriber::with_default(subscriber, || {
+            [
+                tracing::enabled!(tracing::Level::ERROR),
+                tracing::enabled!(tracing::Level::WARN),
+                tracing::enabled!(tracing::Level::INFO),
+                tracing::enabled!(tracing::Level::DEBUG),
+                tracing::enabled!(tracing::Level::TRACE),
+            ]
+        })
+    }
+
+    #[test]
+    fn to_env_filter() {
+        let filter = Verbosity::<InfoLevel>::new(0, 0).to_env_filter();
+        assert_eq!(filter.max_level_hint(), Some(LevelFilter::INFO));
+        assert_eq!(
+            enabled(Verbosity::<InfoLevel>::new(0, 0).to_env_filter()),
+            [true, true, true, false, false]
+        );
+
+        let filter = Verbosity::<InfoLevel>::silent().to_env_filter();
+        assert_eq!(filter.max_level_hint(), Some(LevelFilter::OFF));
+        assert_eq!(
+            enabled(Verbosity::<InfoLevel>::silent().to_env_filter()),
+            [false; 5]
+        );
+    }
+
+    #[test]
+    fn parse_tracing_directive() {
+        for filter in Verbosity::tracing_levels() {
+            let verbosity = Verbosity::<InfoLevel>::from(filter);
+            let directive = verbosity.parse_tracing_directive().unwrap();
+            assert_eq!(directive.to_string(), verbosity.tracing_level_directive());
+
+            let filter = EnvFilter::default().add_directive(directive);
+            assert_eq!(
+                filter.max_level_hint(),
+                Some(verbosity.tracing_level_filter())
+            );
+        }
+    }
+
+    #[test]
+    fn merge_into_env_filter() {
+        let verbosity = Verbosity::<InfoLevel>::new(0, 1);
+        let filter = verbosity.merge_into_env_filter(EnvFilter::new("other_crate=trace"));
+        assert_eq!(filter.max_level_hint(), Some(LevelFilter::TRACE));
+        let filter = verbosity.merge_into_env_filter(EnvFilter::new("other_crate=trace"));
+        assert_eq!(enabled(filter), [true, true, false, false, false]);
+    }
+
+    #[test]
+    fn combines_env_filter_with() {
+        let verbosity = Verbosity::<InfoLevel>::new(0, 1);
+        let subscriber = tracing_subscriber::registry()
+            .with(verbosity.combines_env_filter_with("info,noisy=error,chatty=trace"));
+        tracing::subscriber::with_default(subscriber, || {
+            // The global level comes from the verbosity
+            assert!(tracing::enabled!(tracing::Level::WARN));
+            assert!(!tracing::enabled!(tracing::Level::INFO));
+            // Per-module directives take precedence
+            assert!(tracing::enabled!(target: "chatty", tracing::Level::TRACE));
+            assert!(!tracing::enabled!(target: "noisy", tracing::Level::WARN));
+        });
+
+        let filter = verbosity.combines_env_filter_with("");
+        assert_eq!(filter.max_level_hint(), Some(LevelFilter::WARN));
+    }
+}
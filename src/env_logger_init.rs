@@ -0,0 +1,36 @@
+use crate::LogLevel;
+use crate::Verbosity;
+
+impl<L: LogLevel> Verbosity<L> {
+    /// Install a global `env_logger` at [`Verbosity::log_level_filter`]
+    ///
+    /// ```rust,no_run
+    /// # use clap::Parser;
+    /// # use clap_verbosity_flag::Verbosity;
+    /// #
+    /// # /// Le CLI
+    /// # #[derive(Debug, Parser)]
+    /// # struct Cli {
+    /// #     #[command(flatten)]
+    /// #     verbose: Verbosity,
+    /// # }
+    /// let cli = Cli::parse();
+    /// cli.verbose.init_env_logger();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If a global logger was already installed, see [`Verbosity::try_init_env_logger`]
+    pub fn init_env_logger(&self) {
+        env_logger::Builder::new()
+            .filter_level(self.log_level_filter())
+            .init();
+    }
+
+    /// Like [`Verbosity::init_env_logger`], failing if a global logger was already installed
+    pub fn try_init_env_logger(&self) -> Result<(), log::SetLoggerError> {
+        env_logger::Builder::new()
+            .filter_level(self.log_level_filter())
+            .try_init()
+    }
+}
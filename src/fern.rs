@@ -0,0 +1,59 @@
+use crate::LogLevel;
+use crate::Verbosity;
+
+impl<L: LogLevel> Verbosity<L> {
+    /// Get the level filter for a [`fern::Dispatch`].
+    ///
+    /// This is the same as [`Verbosity::log_level_filter`].
+    pub fn fern_level(&self) -> log::LevelFilter {
+        self.log_level_filter()
+    }
+
+    /// Set the level of `dispatch` to [`Verbosity::fern_level`]
+    ///
+    /// ```rust,no_run
+    /// # use clap::Parser;
+    /// # use clap_verbosity_flag::Verbosity;
+    /// #
+    /// # /// Le CLI
+    /// # #[derive(Debug, Parser)]
+    /// # struct Cli {
+    /// #     #[command(flatten)]
+    /// #     verbose: Verbosity,
+    /// # }
+    /// let cli = Cli::parse();
+    /// cli.verbose
+    ///     .apply_to_fern(fern::Dispatch::new())
+    ///     .chain(std::io::stdout())
+    ///     .apply()
+    ///     .unwrap();
+    /// ```
+    pub fn apply_to_fern(&self, dispatch: fern::Dispatch) -> fern::Dispatch {
+        dispatch.level(self.fern_level())
+    }
+
+    /// Install a global `fern` logger writing to stderr at [`Verbosity::fern_level`]
+    pub fn init_fern_logger(&self) -> Result<(), fern::InitError> {
+        self.apply_to_fern(fern::Dispatch::new())
+            .chain(std::io::stderr())
+            .apply()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::InfoLevel;
+
+    #[test]
+    fn fern_level() {
+        for verbosity in [
+            Verbosity::<InfoLevel>::new(0, 0),
+            Verbosity::new(0, 3),
+            Verbosity::new(2, 0),
+        ] {
+            assert_eq!(verbosity.fern_level(), verbosity.log_level_filter());
+        }
+    }
+}
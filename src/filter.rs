@@ -0,0 +1,127 @@
+use crate::LogLevel;
+use crate::Verbosity;
+
+/// A [`log::Log`] that drops records above [`Verbosity::log_level_filter`] before passing them
+/// to `inner`
+///
+/// Unlike [`Verbosity::apply_log_max_level`], this filters each record, so it can be combined
+/// with other loggers that keep their own levels.
+///
+/// ```rust
+/// use clap_verbosity_flag::{InfoLevel, Verbosity, VerbosityFilter};
+/// use log::Log;
+///
+/// # struct Stderr;
+/// # impl Log for Stderr {
+/// #     fn enabled(&self, _: &log::Metadata<'_>) -> bool { true }
+/// #     fn log(&self, record: &log::Record<'_>) { eprintln!("{}", record.args()) }
+/// #     fn flush(&self) {}
+/// # }
+/// let filter = VerbosityFilter::new(Verbosity::<InfoLevel>::new(0, 0), Stderr);
+/// let debug = log::Metadata::builder().level(log::Level::Debug).build();
+/// assert!(!filter.enabled(&debug));
+/// ```
+#[derive(Debug)]
+pub struct VerbosityFilter<L: LogLevel, I: log::Log> {
+    verbosity: Verbosity<L>,
+    inner: I,
+}
+
+impl<L: LogLevel, I: log::Log> VerbosityFilter<L, I> {
+    /// Filter the records passed to `inner` by `verbosity`
+    pub fn new(verbosity: Verbosity<L>, inner: I) -> Self {
+        VerbosityFilter { verbosity, inner }
+    }
+
+    /// Unwrap the underlying logger
+    pub fn into_inner(self) -> I {
+        self.inner
+    }
+}
+
+impl<L: LogLevel + Send + Sync, I: log::Log> log::Log for VerbosityFilter<L, I> {
+    fn enabled(&self, metadata: &log::Metadata<'_>) -> bool {
+        metadata.level() <= self.verbosity.log_level_filter() && self.inner.enabled(metadata)
+    }
+
+    fn log(&self, record: &log::Record<'_>) {
+        if self.enabled(record.metadata()) {
+            self.inner.log(record);
+        }
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+
+    use log::Log;
+
+    use super::*;
+    use crate::ErrorLevel;
+    use crate::InfoLevel;
+
+    #[derive(Default)]
+    struct MockLogger {
+        records: Mutex<Vec<log::Level>>,
+        flushes: AtomicUsize,
+    }
+
+    impl Log for MockLogger {
+        fn enabled(&self, metadata: &log::Metadata<'_>) -> bool {
+            metadata.target() != "ignored"
+        }
+
+        fn log(&self, record: &log::Record<'_>) {
+            self.records.lock().unwrap().push(record.level());
+        }
+
+        fn flush(&self) {
+            self.flushes.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    fn log_all<L: LogLevel + Send + Sync>(filter: &VerbosityFilter<L, MockLogger>) {
+        for level in Verbosity::levels().flatten() {
+            filter.log(&log::Record::builder().level(level).build());
+        }
+    }
+
+    #[test]
+    fn filters_records() {
+        let filter = VerbosityFilter::new(Verbosity::<InfoLevel>::new(0, 0), MockLogger::default());
+        log_all(&filter);
+        assert_eq!(
+            *filter.inner.records.lock().unwrap(),
+            [log::Level::Error, log::Level::Warn, log::Level::Info]
+        );
+
+        let filter =
+            VerbosityFilter::new(Verbosity::<ErrorLevel>::new(0, 1), MockLogger::default());
+        log_all(&filter);
+        assert!(filter.into_inner().records.into_inner().unwrap().is_empty());
+    }
+
+    #[test]
+    fn enabled() {
+        let filter = VerbosityFilter::new(Verbosity::<InfoLevel>::new(1, 0), MockLogger::default());
+        let metadata = |level, target| log::Metadata::builder().level(level).target(target).build();
+        assert!(filter.enabled(&metadata(log::Level::Debug, "app")));
+        assert!(!filter.enabled(&metadata(log::Level::Trace, "app")));
+        // The inner logger still has a say
+        assert!(!filter.enabled(&metadata(log::Level::Error, "ignored")));
+    }
+
+    #[test]
+    fn flush() {
+        let filter = VerbosityFilter::new(Verbosity::<ErrorLevel>::silent(), MockLogger::default());
+        filter.flush();
+        filter.flush();
+        assert_eq!(filter.inner.flushes.load(Ordering::SeqCst), 2);
+    }
+}
@@ -0,0 +1,41 @@
+use crate::LogLevel;
+use crate::Verbosity;
+
+impl<L: LogLevel> Verbosity<L> {
+    /// Get a `flexi_logger` specification for [`Verbosity::log_level`], like `info` or `off`
+    pub fn flexi_spec_string(&self) -> String {
+        crate::level_name(self.log_verbosity()).to_owned()
+    }
+
+    /// Start a global `flexi_logger` logger at [`Verbosity::flexi_spec_string`]
+    ///
+    /// Keep the returned handle alive for as long as you log.  It can also change the level at
+    /// runtime, with [`flexi_logger::LoggerHandle::parse_new_spec`].
+    pub fn init_flexi_logger(
+        &self,
+    ) -> Result<flexi_logger::LoggerHandle, flexi_logger::FlexiLoggerError> {
+        flexi_logger::Logger::try_with_str(self.flexi_spec_string())?.start()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ErrorLevel;
+
+    #[test]
+    fn flexi_spec_string() {
+        for (verbose, quiet, expected) in [
+            (0, 1, "off"),
+            (0, 0, "error"),
+            (1, 0, "warn"),
+            (2, 0, "info"),
+            (3, 0, "debug"),
+            (4, 0, "trace"),
+        ] {
+            let spec = Verbosity::<ErrorLevel>::new(verbose, quiet).flexi_spec_string();
+            assert_eq!(spec, expected);
+            flexi_logger::LogSpecification::parse(&spec).unwrap();
+        }
+    }
+}
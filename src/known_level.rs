@@ -0,0 +1,221 @@
+use std::fmt;
+
+use crate::LogLevel;
+use crate::ParseLevelError;
+use crate::Verbosity;
+
+/// A level that doesn't depend on `log` or `tracing` being enabled
+///
+/// Levels are ordered from [`KnownLevel::Off`], the quietest, to [`KnownLevel::Trace`].
+///
+/// ```rust
+/// use clap_verbosity_flag::{InfoLevel, KnownLevel, Verbosity};
+///
+/// let verbosity = Verbosity::<InfoLevel>::new(1, 0);
+/// assert_eq!(verbosity.known_level(), KnownLevel::Debug);
+/// assert_eq!("WARN".parse::<KnownLevel>().unwrap(), KnownLevel::Warn);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum KnownLevel {
+    Off,
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl KnownLevel {
+    fn from_verbosity(verbosity: i8) -> Self {
+        match verbosity {
+            i8::MIN..=-1 => KnownLevel::Off,
+            0 => KnownLevel::Error,
+            1 => KnownLevel::Warn,
+            2 => KnownLevel::Info,
+            3 => KnownLevel::Debug,
+            4.. => KnownLevel::Trace,
+        }
+    }
+
+    pub(crate) fn as_i8(self) -> i8 {
+        match self {
+            KnownLevel::Off => -1,
+            KnownLevel::Error => 0,
+            KnownLevel::Warn => 1,
+            KnownLevel::Info => 2,
+            KnownLevel::Debug => 3,
+            KnownLevel::Trace => 4,
+        }
+    }
+}
+
+impl<L: LogLevel> Verbosity<L> {
+    /// Get the level, regardless of which logging backend is enabled
+    ///
+    /// Like [`Verbosity::level_name`], this is bounded by [`LogLevel::min_level`] and
+    /// [`LogLevel::max_level`].
+    pub fn known_level(&self) -> KnownLevel {
+        KnownLevel::from_verbosity(self.level_verbosity())
+    }
+}
+
+impl<L: LogLevel> From<Verbosity<L>> for KnownLevel {
+    fn from(verbosity: Verbosity<L>) -> Self {
+        verbosity.known_level()
+    }
+}
+
+#[cfg(feature = "log")]
+impl From<KnownLevel> for log::LevelFilter {
+    fn from(level: KnownLevel) -> Self {
+        crate::level_enum_log(level.as_i8())
+            .map(|l| l.to_level_filter())
+            .unwrap_or(log::LevelFilter::Off)
+    }
+}
+
+#[cfg(feature = "log")]
+impl From<KnownLevel> for Option<log::Level> {
+    fn from(level: KnownLevel) -> Self {
+        crate::level_enum_log(level.as_i8())
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl From<KnownLevel> for tracing_subscriber::filter::LevelFilter {
+    fn from(level: KnownLevel) -> Self {
+        crate::level_enum_tracing(level.as_i8())
+    }
+}
+
+/// Shows the lowercase name, like `info` or `off`
+impl fmt::Display for KnownLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(crate::level_name(self.as_i8()))
+    }
+}
+
+/// Accepts the same inputs as [`Verbosity`]'s `FromStr`, see [`ParseLevelError::valid_values`]
+impl std::str::FromStr for KnownLevel {
+    type Err = ParseLevelError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match crate::parse_level_value(input) {
+            Some(verbosity) => Ok(KnownLevel::from_verbosity(verbosity)),
+            None => Err(ParseLevelError {
+                input: input.to_owned(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ErrorLevel;
+
+    crate::impl_log_level!(CappedLevel, default: info, min: warn, max: debug);
+
+    const ALL: [KnownLevel; 6] = [
+        KnownLevel::Off,
+        KnownLevel::Error,
+        KnownLevel::Warn,
+        KnownLevel::Info,
+        KnownLevel::Debug,
+        KnownLevel::Trace,
+    ];
+
+    #[test]
+    fn from_verbosity() {
+        for (quiet, verbose, expected) in [
+            (1, 0, KnownLevel::Off),
+            (0, 0, KnownLevel::Error),
+            (0, 2, KnownLevel::Info),
+            (0, 10, KnownLevel::Trace),
+        ] {
+            let verbosity = Verbosity::<ErrorLevel>::new(verbose, quiet);
+            assert_eq!(verbosity.known_level(), expected);
+            assert_eq!(KnownLevel::from(verbosity), expected);
+        }
+    }
+
+    #[test]
+    fn capped() {
+        let verbosity = Verbosity::<CappedLevel>::new(3, 0);
+        assert_eq!(verbosity.known_level(), KnownLevel::Debug);
+        assert_eq!(verbosity.known_level().to_string(), verbosity.level_name());
+
+        let verbosity = Verbosity::<CappedLevel>::new(0, 3);
+        assert_eq!(verbosity.known_level(), KnownLevel::Warn);
+    }
+
+    #[test]
+    fn parse_like_verbosity() {
+        for input in ["info", " TRACE ", "-1", "4", "5", "loud", ""] {
+            assert_eq!(
+                input.parse::<KnownLevel>().ok(),
+                input
+                    .parse::<Verbosity<ErrorLevel>>()
+                    .ok()
+                    .map(|verbosity| verbosity.known_level()),
+                "{input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn display_and_parse() {
+        for level in ALL {
+            assert_eq!(level.to_string().parse::<KnownLevel>().unwrap(), level);
+            assert_eq!(
+                level.to_string().to_uppercase().parse::<KnownLevel>(),
+                Ok(level)
+            );
+        }
+        assert_eq!(KnownLevel::Info.to_string(), "info");
+        assert_eq!(" -1 ".parse::<KnownLevel>(), Ok(KnownLevel::Off));
+        assert_eq!("loud".parse::<KnownLevel>().unwrap_err().input(), "loud");
+    }
+
+    #[test]
+    fn ordering() {
+        assert!(ALL.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    #[cfg(feature = "log")]
+    fn log_conversions() {
+        for level in ALL {
+            let verbosity = Verbosity::<ErrorLevel>::from_count(level.as_i8());
+            assert_eq!(Option::<log::Level>::from(level), verbosity.log_level());
+            assert_eq!(log::LevelFilter::from(level), verbosity.log_level_filter());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "tracing")]
+    fn tracing_conversions() {
+        for level in ALL {
+            let verbosity = Verbosity::<ErrorLevel>::from_count(level.as_i8());
+            assert_eq!(
+                tracing_subscriber::filter::LevelFilter::from(level),
+                verbosity.tracing_level_filter()
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        assert_eq!(
+            serde_json::to_string(&KnownLevel::Debug).unwrap(),
+            "\"debug\""
+        );
+        assert_eq!(
+            serde_json::from_str::<KnownLevel>("\"off\"").unwrap(),
+            KnownLevel::Off
+        );
+    }
+}
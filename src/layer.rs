@@ -0,0 +1,98 @@
+use std::sync::Arc;
+use std::sync::RwLock;
+
+use tracing_core::subscriber::Interest;
+use tracing_core::Metadata;
+use tracing_core::Subscriber;
+use tracing_subscriber::layer::Context;
+
+use crate::LogLevel;
+use crate::Verbosity;
+
+/// A [`Layer`][tracing_subscriber::Layer] that filters events by a [`Verbosity`] that can be
+/// changed at runtime
+///
+/// ```rust
+/// use clap_verbosity_flag::{InfoLevel, Verbosity, VerbosityLayer};
+/// use tracing_subscriber::layer::SubscriberExt as _;
+///
+/// let (layer, handle) = VerbosityLayer::new(Verbosity::<InfoLevel>::new(0, 0));
+/// let subscriber = tracing_subscriber::registry().with(layer);
+/// tracing::subscriber::with_default(subscriber, || {
+///     assert!(!tracing::enabled!(tracing::Level::DEBUG));
+///     handle.write().unwrap().increment();
+///     assert!(tracing::enabled!(tracing::Level::DEBUG));
+/// });
+/// ```
+#[derive(Debug)]
+pub struct VerbosityLayer<L: LogLevel> {
+    verbosity: Arc<RwLock<Verbosity<L>>>,
+}
+
+impl<L: LogLevel> VerbosityLayer<L> {
+    /// Filter by `verbosity`, returning a handle for updating it
+    pub fn new(verbosity: Verbosity<L>) -> (Self, Arc<RwLock<Verbosity<L>>>) {
+        let verbosity = Arc::new(RwLock::new(verbosity));
+        let layer = VerbosityLayer {
+            verbosity: verbosity.clone(),
+        };
+        (layer, verbosity)
+    }
+}
+
+impl<S: Subscriber, L: LogLevel + Send + Sync + 'static> tracing_subscriber::Layer<S>
+    for VerbosityLayer<L>
+{
+    fn register_callsite(&self, _metadata: &'static Metadata<'static>) -> Interest {
+        // The level can change, so don't let the callsite cache the result
+        Interest::sometimes()
+    }
+
+    fn enabled(&self, metadata: &Metadata<'_>, _ctx: Context<'_, S>) -> bool {
+        let verbosity = match self.verbosity.read() {
+            Ok(verbosity) => verbosity,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        verbosity.tracing_level_filter() >= *metadata.level()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use tracing_subscriber::layer::SubscriberExt as _;
+
+    use super::*;
+    use crate::ErrorLevel;
+
+    fn enabled() -> [bool; 5] {
+        [
+            tracing::enabled!(tracing::Level::ERROR),
+            tracing::enabled!(tracing::Level::WARN),
+            tracing::enabled!(tracing::Level::INFO),
+            tracing::enabled!(tracing::Level::DEBUG),
+            tracing::enabled!(tracing::Level::TRACE),
+        ]
+    }
+
+    #[test]
+    fn level_transitions() {
+        let (layer, handle) = VerbosityLayer::new(Verbosity::<ErrorLevel>::silent());
+        let subscriber = tracing_subscriber::registry().with(layer);
+        tracing::subscriber::with_default(subscriber, || {
+            assert_eq!(enabled(), [false; 5]);
+            for expected in [
+                [true, false, false, false, false],
+                [true, true, false, false, false],
+                [true, true, true, false, false],
+                [true, true, true, true, false],
+                [true; 5],
+            ] {
+                handle.write().unwrap().increment();
+                assert_eq!(enabled(), expected);
+            }
+
+            *handle.write().unwrap() = Verbosity::new(1, 0);
+            assert_eq!(enabled(), [true, true, false, false, false]);
+        });
+    }
+}
@@ -0,0 +1,103 @@
+use crate::KnownLevel;
+use crate::LogLevel;
+use crate::Verbosity;
+
+/// A level picked by name, like `--level debug`, instead of counting `--verbose`
+///
+/// ```rust,no_run
+/// # use clap::Parser;
+/// use clap_verbosity_flag::{InfoLevel, LevelValue, Verbosity};
+///
+/// /// Le CLI
+/// #[derive(Debug, Parser)]
+/// struct Cli {
+///     #[arg(long, value_enum)]
+///     level: Option<LevelValue>,
+/// }
+///
+/// let cli = Cli::parse();
+/// let verbosity = cli
+///     .level
+///     .map(LevelValue::to_verbosity::<InfoLevel>)
+///     .unwrap_or_default();
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, clap::ValueEnum)]
+pub enum LevelValue {
+    Off,
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl LevelValue {
+    /// Create a [`Verbosity`] that resolves to this level
+    pub fn to_verbosity<L: LogLevel>(self) -> Verbosity<L> {
+        Verbosity::from_verbosity(KnownLevel::from(self).as_i8())
+    }
+}
+
+impl From<LevelValue> for KnownLevel {
+    fn from(level: LevelValue) -> Self {
+        match level {
+            LevelValue::Off => KnownLevel::Off,
+            LevelValue::Error => KnownLevel::Error,
+            LevelValue::Warn => KnownLevel::Warn,
+            LevelValue::Info => KnownLevel::Info,
+            LevelValue::Debug => KnownLevel::Debug,
+            LevelValue::Trace => KnownLevel::Trace,
+        }
+    }
+}
+
+#[cfg(feature = "log")]
+impl From<LevelValue> for Option<log::Level> {
+    fn from(level: LevelValue) -> Self {
+        KnownLevel::from(level).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use clap::ValueEnum as _;
+
+    use super::*;
+    use crate::InfoLevel;
+
+    #[test]
+    fn value_enum() {
+        let names = LevelValue::value_variants()
+            .iter()
+            .map(|value| value.to_possible_value().unwrap().get_name().to_owned())
+            .collect::<Vec<_>>();
+        assert_eq!(names, crate::LEVEL_NAMES);
+        assert_eq!(LevelValue::from_str("DEBUG", true), Ok(LevelValue::Debug));
+    }
+
+    #[test]
+    fn to_verbosity() {
+        for (value, name) in LevelValue::value_variants().iter().zip(crate::LEVEL_NAMES) {
+            let verbosity = value.to_verbosity::<InfoLevel>();
+            assert_eq!(verbosity.level_name(), name);
+            assert_eq!(verbosity.known_level(), KnownLevel::from(*value));
+            #[cfg(feature = "log")]
+            assert_eq!(verbosity.log_level(), Option::<log::Level>::from(*value));
+        }
+    }
+
+    #[test]
+    fn arg() {
+        use clap::Parser;
+
+        #[derive(Debug, Parser)]
+        struct Cli {
+            #[arg(long, value_enum)]
+            level: Option<LevelValue>,
+        }
+
+        let cli = Cli::parse_from(["cli", "--level", "warn"]);
+        assert_eq!(cli.level, Some(LevelValue::Warn));
+        assert!(Cli::try_parse_from(["cli", "--level", "loud"]).is_err());
+    }
+}
@@ -27,10 +27,15 @@
 //! #     #[command(flatten)]
 //! #     verbose: Verbosity,
 //! # }
+//! # #[cfg(feature = "log")]
+//! # fn main() {
 //! let cli = Cli::parse();
 //! env_logger::Builder::new()
 //!     .filter_level(cli.verbose.log_level_filter())
 //!     .init();
+//! # }
+//! # #[cfg(not(feature = "log"))]
+//! # fn main() {}
 //! ```
 //!
 //! Or your tracing subscriber:
@@ -44,16 +49,32 @@
 //! #     #[command(flatten)]
 //! #     verbose: Verbosity,
 //! # }
+//! # #[cfg(feature = "tracing")]
+//! # fn main() {
 //! let cli = Cli::parse();
 //! tracing_subscriber::fmt()
 //!     .with_max_level(cli.verbose.tracing_level_filter())
 //!     .init();
+//! # }
+//! # #[cfg(not(feature = "tracing"))]
+//! # fn main() {}
 //! ```
 //!
 //! # Features
 //!
 //! - `log` (default) enables the `log`-based logging
-//! - `tracing` enables the `tracing`-based logging
+//! - `tracing` enables the `tracing`-based logging, including
+//!   [`Verbosity::init_tracing_subscriber`]
+//! - `serde` enables (de)serializing [`Verbosity`] as `{"verbose": 2, "quiet": 0}`
+//! - `arbitrary` enables generating [`Verbosity`] for fuzzing
+//! - `proptest` enables [`verbosity_strategy`] for property testing
+//! - `slog` enables [`Verbosity::slog_level`] and [`Verbosity::apply_to_slog_logger`]
+//! - `fern` enables [`Verbosity::apply_to_fern`] and [`Verbosity::init_fern_logger`]
+//! - `simplelog` enables [`Verbosity::init_simplelog`] and [`Verbosity::init_termlog`]
+//! - `flexi-logger` enables [`Verbosity::init_flexi_logger`]
+//! - `opentelemetry` enables [`Verbosity::otel_severity`] and [`Verbosity::to_otel_log_record`]
+//! - `env-logger` enables [`Verbosity::init_env_logger`]
+//! - `env-filter` enables [`Verbosity::to_env_filter`] and [`Verbosity::parse_tracing_directive`]
 //!
 //! # Logging
 //!
@@ -69,53 +90,182 @@
 //! You can also customize the default logging level:
 //! ```rust,no_run
 //! # use clap::Parser;
-//! use clap_verbosity_flag::{Verbosity, LogLevel};
+//! use clap_verbosity_flag::{Verbosity, InfoLevel};
 //!
 //! /// Le CLI
 //! #[derive(Debug, Parser)]
 //! struct Cli {
 //!     #[command(flatten)]
-//!     verbose: Verbosity<LogLevel>,
+//!     verbose: Verbosity<InfoLevel>,
 //! }
 //! ```
 //!
-//! Or implement [`LogLevel`] yourself for more control.
+//! Or implement [`LogLevel`] yourself for more control, or declare one with [`verbosity_level!`]
+//! or, to also set its other options, [`impl_log_level!`].
+//! For a default picked at runtime, like from a config file, see [`DynamicLevel`].
+//!
+//! To also accept an explicit count, like `--verbose=3`, flatten [`VerbosityCount`] instead.
+//! To allow `--verbose` and `--quiet` to be combined, flatten [`VerbosityPermissive`].
+//! To also accept a level by name, like `--log-level=debug`, flatten [`LogLevelArg`].
+//! To only allow adding output, without `--quiet`, flatten [`VerbosityNoQuiet`].
+//! To only allow suppressing output, without `--verbose`, flatten [`VerbosityNoVerbose`].
+//! To give each subcommand its own, non-global flags, flatten [`LocalVerbosity`].
+//! To give each subsystem its own flags, like `--parser-verbose`, register it with
+//! [`MultiVerbosity`].
+//!
+//! To construct a [`Verbosity`] outside of argument parsing, see [`Verbosity::builder`].
+//! To filter the records reaching another [`log::Log`], wrap it in a [`VerbosityFilter`].
+//! To change the `tracing` level at runtime, add a [`VerbosityLayer`].
+//! To work with levels without depending on `log` or `tracing`, see [`KnownLevel`].
+//! To pick a level by name with your own argument, like `--level debug`, see [`LevelValue`].
+//! To track where a level came from when combining several, see [`Verbosity::with_context`].
 
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 
 #[cfg(feature = "tracing")]
 use tracing_subscriber::filter::LevelFilter;
 
-#[derive(clap::Args, Debug, Clone)]
+#[doc(hidden)]
+pub mod __macro_support {
+    #[cfg(feature = "log")]
+    pub use log::Level;
+    #[cfg(feature = "tracing")]
+    pub use tracing_subscriber::filter::LevelFilter;
+}
+
+mod annotated;
+mod builder;
+mod context;
+mod count;
+#[cfg(feature = "log")]
+mod dynamic;
+#[cfg(feature = "env-filter")]
+mod env_filter;
+#[cfg(feature = "env-logger")]
+mod env_logger_init;
+#[cfg(feature = "fern")]
+mod fern;
+#[cfg(feature = "log")]
+mod filter;
+#[cfg(feature = "flexi-logger")]
+mod flexi_logger;
+mod known_level;
+#[cfg(feature = "tracing")]
+mod layer;
+mod level_value;
+mod local;
+mod log_level_arg;
+mod macros;
+mod multi;
+mod no_quiet;
+mod no_verbose;
+#[cfg(feature = "opentelemetry")]
+mod otel;
+mod permissive;
+#[cfg(feature = "log")]
+mod range;
+#[cfg(feature = "simplelog")]
+mod simplelog;
+#[cfg(feature = "slog")]
+mod slog;
+#[cfg(feature = "tracing")]
+mod tracing_fmt;
+
+pub use annotated::AnnotatedVerbosity;
+pub use builder::VerbosityBuilder;
+pub use builder::VerbosityError;
+pub use context::VerbosityContext;
+pub use count::VerbosityCount;
+#[cfg(feature = "log")]
+pub use dynamic::DynamicLevel;
+#[cfg(feature = "log")]
+pub use filter::VerbosityFilter;
+pub use known_level::KnownLevel;
+#[cfg(feature = "tracing")]
+pub use layer::VerbosityLayer;
+pub use level_value::LevelValue;
+pub use local::LocalVerbosity;
+pub use log_level_arg::LogLevelArg;
+pub use multi::MultiVerbosity;
+pub use no_quiet::VerbosityNoQuiet;
+pub use no_verbose::VerbosityNoVerbose;
+pub use permissive::VerbosityPermissive;
+#[cfg(feature = "log")]
+pub use range::VerbosityRange;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Verbosity<L: LogLevel = ErrorLevel> {
-    #[arg(
-        long,
-        short = 'v',
-        action = clap::ArgAction::Count,
-        global = true,
-        help = L::verbose_help(),
-        long_help = L::verbose_long_help(),
-    )]
     verbose: u8,
-
-    #[arg(
-        long,
-        short = 'q',
-        action = clap::ArgAction::Count,
-        global = true,
-        help = L::quiet_help(),
-        long_help = L::quiet_long_help(),
-        conflicts_with = "verbose",
-    )]
     quiet: u8,
-
-    #[arg(skip)]
+    #[cfg_attr(feature = "serde", serde(skip))]
     phantom: std::marker::PhantomData<L>,
 }
 
+impl<L: LogLevel> clap::FromArgMatches for Verbosity<L> {
+    fn from_arg_matches(matches: &clap::ArgMatches) -> Result<Self, clap::Error> {
+        let mut verbosity = Self::new(0, 0);
+        verbosity.update_from_arg_matches(matches)?;
+        Ok(verbosity)
+    }
+
+    fn update_from_arg_matches(&mut self, matches: &clap::ArgMatches) -> Result<(), clap::Error> {
+        let parsed = Verbosity::<L>::new(matches.get_count(VERBOSE), matches.get_count(QUIET));
+        *self = parsed.with_level_env();
+        Ok(())
+    }
+}
+
+impl<L: LogLevel> clap::Args for Verbosity<L> {
+    fn augment_args(cmd: clap::Command) -> clap::Command {
+        cmd.arg(verbose_arg::<L>()).arg(quiet_arg::<L>())
+    }
+
+    fn augment_args_for_update(cmd: clap::Command) -> clap::Command {
+        Self::augment_args(cmd)
+    }
+}
+
+const VERBOSE: &str = "verbose";
+const QUIET: &str = "quiet";
+
+fn verbose_arg<L: LogLevel>() -> clap::Arg {
+    clap::Arg::new(VERBOSE)
+        .long(L::verbose_long_name())
+        .visible_aliases(L::verbose_aliases().iter().copied())
+        .short(L::verbose_short_char())
+        .action(clap::ArgAction::Count)
+        .global(true)
+        .help(L::verbose_help())
+        .long_help(L::verbose_long_help())
+}
+
+fn quiet_arg<L: LogLevel>() -> clap::Arg {
+    let arg = clap::Arg::new(QUIET)
+        .long(L::quiet_long_name())
+        .visible_aliases(L::quiet_aliases().iter().copied())
+        .short(L::quiet_short_char())
+        .action(clap::ArgAction::Count)
+        .global(true)
+        .help(L::quiet_help())
+        .long_help(L::quiet_long_help());
+    if L::verbose_conflicts_with_quiet() {
+        arg.conflicts_with(VERBOSE)
+    } else {
+        arg
+    }
+}
+
 impl<L: LogLevel> Verbosity<L> {
     /// Create a new verbosity instance by explicitly setting the values
-    pub fn new(verbose: u8, quiet: u8) -> Self {
+    ///
+    /// This is a `const fn`, so it can be used for constants:
+    /// ```rust
+    /// use clap_verbosity_flag::{InfoLevel, Verbosity};
+    ///
+    /// const QUIET_MODE: Verbosity<InfoLevel> = Verbosity::new(0, 2);
+    /// ```
+    pub const fn new(verbose: u8, quiet: u8) -> Self {
         Verbosity {
             verbose,
             quiet,
@@ -123,185 +273,3797 @@ impl<L: LogLevel> Verbosity<L> {
         }
     }
 
+    /// Create a verbosity instance that disables all output
+    pub fn silent() -> Self {
+        Self::from_verbosity(-1)
+    }
+
+    /// Create a verbosity instance that enables all output
+    pub fn max_verbose() -> Self {
+        Self::from_verbosity(4)
+    }
+
+    /// Number of times `--verbose` was passed
+    pub fn verbose_count(&self) -> u8 {
+        self.verbose
+    }
+
+    /// Number of times `--quiet` was passed
+    pub fn quiet_count(&self) -> u8 {
+        self.quiet
+    }
+
+    /// Number of times `--verbose` was passed, minus the number of times `--quiet` was
+    ///
+    /// Unlike [`Verbosity::as_i8`], this is not offset by `L`'s default nor saturated; `as_i8()` is
+    /// `net_count()` times [`LogLevel::step_size`] plus the value of `L`'s default level, clamped to
+    /// `-1..=4`, when the counts are within [`LogLevel::max_verbose_steps`] and
+    /// [`LogLevel::max_quiet_steps`].
+    pub fn net_count(&self) -> i16 {
+        self.verbose as i16 - self.quiet as i16
+    }
+
     #[cfg(feature = "log")]
     /// Get the log level.
     ///
-    /// `None` means all output is disabled.
+    /// `None` means all output is disabled.  This is bounded by [`LogLevel::min_level`] and
+    /// [`LogLevel::max_level`].
     pub fn log_level(&self) -> Option<log::Level> {
-        level_enum_log(self.verbosity())
+        level_enum_log(self.log_verbosity())
     }
 
     #[cfg(feature = "tracing")]
     pub fn tracing_level(&self) -> LevelFilter {
-        level_enum_tracing(self.verbosity())
+        level_enum_tracing(self.tracing_verbosity())
     }
 
     #[cfg(feature = "log")]
     /// Get the log level filter.
     pub fn log_level_filter(&self) -> log::LevelFilter {
-        level_enum_log(self.verbosity())
+        level_enum_log(self.log_verbosity())
             .map(|l| l.to_level_filter())
             .unwrap_or(log::LevelFilter::Off)
     }
 
+    #[cfg(feature = "log")]
+    /// Set [`log::max_level`] to [`Verbosity::log_level_filter`]
+    ///
+    /// This is for when a logger is already installed.  Records above this level are dropped
+    /// before reaching the logger, but a logger with its own, quieter, filter still applies it.
+    pub fn apply_log_max_level(&self) {
+        log::set_max_level(self.log_level_filter());
+    }
+
+    #[cfg(feature = "log")]
+    /// Like [`Verbosity::apply_log_max_level`], for use alongside fallible logger setup
+    ///
+    /// This never fails.
+    pub fn try_apply_log_max_level(&self) -> Result<(), std::convert::Infallible> {
+        self.apply_log_max_level();
+        Ok(())
+    }
+
     #[cfg(feature = "tracing")]
+    /// Get the tracing level filter.
+    ///
+    /// This is bounded by [`LogLevel::min_tracing_level`] and [`LogLevel::max_tracing_level`].
     pub fn tracing_level_filter(&self) -> LevelFilter {
-        level_enum_tracing(self.verbosity())
+        level_enum_tracing(self.tracing_verbosity())
     }
 
-    /// If the user requested complete silence (i.e. not just no-logging).
-    pub fn is_silent(&self) -> bool {
-        #[cfg(feature = "log")]
-        return self.log_level().is_none();
-        #[cfg(all(feature = "tracing", not(feature = "log")))]
-        return self.tracing_level() == LevelFilter::OFF;
+    #[cfg(feature = "tracing")]
+    /// Get [`Verbosity::tracing_level_filter`] as a `tracing_subscriber` directive, like `info`
+    pub fn tracing_level_directive(&self) -> String {
+        self.tracing_level_filter().to_string()
     }
 
-    fn verbosity(&self) -> i8 {
-        #[cfg(feature = "log")]
-        return level_value_log(L::default_log()) - (self.quiet as i8) + (self.verbose as i8);
-        #[cfg(all(feature = "tracing", not(feature = "log")))]
-        return level_value_tracing(L::default_tracing()) - (self.quiet as i8)
-            + (self.verbose as i8);
+    /// [`Verbosity::as_i8`] bounded by `L`, the level behind every backend-independent accessor
+    ///
+    /// This matches [`Verbosity::log_level`] when `log` is enabled, and
+    /// [`Verbosity::tracing_level_filter`] otherwise.
+    fn level_verbosity(&self) -> i8 {
+        let (min, max) = level_bounds::<L>();
+        self.as_i8().max(min).min(max)
     }
-}
 
-#[cfg(feature = "log")]
-fn level_value_log(level: Option<log::Level>) -> i8 {
-    match level {
-        None => -1,
-        Some(log::Level::Error) => 0,
-        Some(log::Level::Warn) => 1,
-        Some(log::Level::Info) => 2,
-        Some(log::Level::Debug) => 3,
-        Some(log::Level::Trace) => 4,
+    #[cfg(feature = "log")]
+    fn log_verbosity(&self) -> i8 {
+        self.as_i8()
+            .max(level_value_log(L::min_level()))
+            .min(level_value_log(L::max_level()))
     }
-}
 
-#[cfg(feature = "tracing")]
-fn level_value_tracing(level: LevelFilter) -> i8 {
-    match level {
-        LevelFilter::ERROR => 0,
-        LevelFilter::WARN => 1,
-        LevelFilter::INFO => 2,
-        LevelFilter::DEBUG => 3,
-        LevelFilter::TRACE => 4,
-        _ => -1,
+    #[cfg(feature = "tracing")]
+    fn tracing_verbosity(&self) -> i8 {
+        self.as_i8()
+            .max(level_value_tracing(Some(L::min_tracing_level())))
+            .min(level_value_tracing(Some(L::max_tracing_level())))
     }
-}
 
-#[cfg(feature = "log")]
-fn level_enum_log(verbosity: i8) -> Option<log::Level> {
-    match verbosity {
-        i8::MIN..=-1 => None,
-        0 => Some(log::Level::Error),
-        1 => Some(log::Level::Warn),
-        2 => Some(log::Level::Info),
-        3 => Some(log::Level::Debug),
-        4.. => Some(log::Level::Trace),
+    #[cfg(feature = "log")]
+    /// If events at `level` would be logged, like `is_at_least(Level::Info)` for info or more
+    pub fn is_at_least(&self, level: log::Level) -> bool {
+        self.log_verbosity() >= level_value_log(Some(level))
     }
-}
 
-#[cfg(feature = "tracing")]
-fn level_enum_tracing(verbosity: i8) -> LevelFilter {
-    match verbosity {
-        i8::MIN..=-1 => LevelFilter::OFF,
-        0 => LevelFilter::ERROR,
-        1 => LevelFilter::WARN,
-        2 => LevelFilter::INFO,
-        3 => LevelFilter::DEBUG,
-        4.. => LevelFilter::TRACE,
+    #[cfg(feature = "log")]
+    /// If nothing more verbose than `level` would be logged, like `is_at_most(Level::Warn)` for
+    /// warn, error, or off
+    pub fn is_at_most(&self, level: log::Level) -> bool {
+        self.log_verbosity() <= level_value_log(Some(level))
     }
-}
 
-use std::fmt;
+    #[cfg(feature = "log")]
+    /// If an event at `level` for `target` would be logged, like [`log::log_enabled!`]
+    ///
+    /// This is for skipping expensive work before logging.  Only [`Verbosity::log_level_filter`]
+    /// is checked, not per-target overrides from the installed logger, so `target` is currently
+    /// unused.
+    pub fn log_enabled(&self, level: log::Level, target: &str) -> bool {
+        let _ = target;
+        level <= self.log_level_filter()
+    }
 
-impl<L: LogLevel> fmt::Display for Verbosity<L> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.verbosity())
+    #[cfg(feature = "tracing")]
+    /// Like [`Verbosity::is_at_least`], against [`Verbosity::tracing_level_filter`]
+    pub fn is_at_least_tracing(&self, level: tracing_core::Level) -> bool {
+        self.tracing_verbosity() >= level_value_tracing(Some(LevelFilter::from_level(level)))
     }
-}
 
-pub trait LogLevel {
-    #[cfg(feature = "log")]
-    fn default_log() -> Option<log::Level>;
     #[cfg(feature = "tracing")]
-    fn default_tracing() -> Option<LevelFilter>;
+    /// Like [`Verbosity::is_at_most`], against [`Verbosity::tracing_level_filter`]
+    pub fn is_at_most_tracing(&self, level: tracing_core::Level) -> bool {
+        self.tracing_verbosity() <= level_value_tracing(Some(LevelFilter::from_level(level)))
+    }
 
-    fn verbose_help() -> Option<&'static str> {
-        Some("More output per occurrence")
+    /// If the user asked for more output than `L`'s default, regardless of the resulting level
+    pub fn above_default(&self) -> bool {
+        self.verbose > 0
     }
 
-    fn verbose_long_help() -> Option<&'static str> {
-        None
+    /// If the user asked for less output than `L`'s default, regardless of the resulting level
+    pub fn below_default(&self) -> bool {
+        self.quiet > 0
     }
 
-    fn quiet_help() -> Option<&'static str> {
-        Some("Less output per occurrence")
+    /// If the user asked for `L`'s default, the same as [`Verbosity::is_default`]
+    pub fn at_default(&self) -> bool {
+        self.is_default()
     }
 
-    fn quiet_long_help() -> Option<&'static str> {
-        None
+    /// If the user asked for more output, regardless of the resulting level
+    #[deprecated(since = "2.1.0", note = "Use `Verbosity::above_default`")]
+    pub fn is_verbose(&self) -> bool {
+        self.above_default()
     }
-}
 
-#[derive(Copy, Clone, Debug, Default)]
-pub struct ErrorLevel;
+    /// If the user asked for less output, regardless of the resulting level
+    #[deprecated(since = "2.1.0", note = "Use `Verbosity::below_default`")]
+    pub fn is_quiet(&self) -> bool {
+        self.below_default()
+    }
 
-impl LogLevel for ErrorLevel {
-    #[cfg(feature = "log")]
-    fn default_log() -> Option<log::Level> {
-        Some(log::Level::Error)
+    /// Command-line arguments that reproduce this level, like `-vvv`
+    ///
+    /// This is useful for passing the verbosity along to a subprocess that also uses
+    /// [`Verbosity<L>`].  Short flags are used when [`LogLevel`] defines them, see
+    /// [`Verbosity::to_roundtrip_long_args`] for the long form.
+    pub fn to_roundtrip_args(&self) -> Vec<String> {
+        let normalized = Self::from_verbosity(self.as_i8());
+        let (short, long, count) = if 0 < normalized.verbose {
+            (
+                L::verbose_short_char(),
+                L::verbose_long_name(),
+                normalized.verbose,
+            )
+        } else {
+            (
+                L::quiet_short_char(),
+                L::quiet_long_name(),
+                normalized.quiet,
+            )
+        };
+        match short {
+            _ if count == 0 => Vec::new(),
+            Some(short) => Vec::from([format!("-{}", short.to_string().repeat(count as usize))]),
+            None => (0..count).map(|_| format!("--{long}")).collect(),
+        }
     }
 
-    #[cfg(feature = "tracing")]
-    fn default_tracing() -> Option<LevelFilter> {
-        Some(LevelFilter::ERROR)
+    /// Command-line arguments that reproduce this level, like `--verbose --verbose --verbose`
+    pub fn to_roundtrip_long_args(&self) -> Vec<String> {
+        let normalized = Self::from_verbosity(self.as_i8());
+        let (long, count) = if 0 < normalized.verbose {
+            (L::verbose_long_name(), normalized.verbose)
+        } else {
+            (L::quiet_long_name(), normalized.quiet)
+        };
+        (0..count).map(|_| format!("--{long}")).collect()
     }
-}
 
-#[derive(Copy, Clone, Debug, Default)]
-pub struct WarnLevel;
+    /// Clear the `--verbose` and `--quiet` counts, returning to `L`'s default level
+    pub fn reset(&mut self) {
+        self.verbose = 0;
+        self.quiet = 0;
+    }
 
-impl LogLevel for WarnLevel {
-    #[cfg(feature = "log")]
-    fn default_log() -> Option<log::Level> {
-        Some(log::Level::Warn)
+    /// If neither `--verbose` nor `--quiet` were passed
+    ///
+    /// This is about the flags, not the level: `-v -q` resolves to the default level but isn't
+    /// the default.
+    pub fn is_default(&self) -> bool {
+        self.verbose == 0 && self.quiet == 0
     }
 
-    #[cfg(feature = "tracing")]
-    fn default_tracing() -> Option<LevelFilter> {
-        Some(LevelFilter::WARN)
+    /// If `--verbose` or `--quiet` were passed, the opposite of [`Verbosity::is_default`]
+    pub fn was_modified(&self) -> bool {
+        !self.is_default()
     }
-}
 
-#[derive(Copy, Clone, Debug, Default)]
-pub struct InfoLevel;
+    /// If [`Verbosity::increment`] would have no effect
+    ///
+    /// That is, the level is `trace`, or [`LogLevel::max_level`] if lower, so more `-v` flags
+    /// won't add output.
+    pub fn is_at_max(&self) -> bool {
+        level_bounds::<L>().1 <= self.level_verbosity()
+    }
 
-impl LogLevel for InfoLevel {
-    #[cfg(feature = "log")]
-    fn default_log() -> Option<log::Level> {
-        Some(log::Level::Info)
+    /// How many levels are more verbose than the current one, `0` at [`LogLevel::max_level`]
+    pub fn levels_above_current(&self) -> usize {
+        (level_bounds::<L>().1 - self.level_verbosity()).max(0) as usize
     }
-    #[cfg(feature = "tracing")]
-    fn default_tracing() -> Option<LevelFilter> {
-        Some(LevelFilter::INFO)
+
+    /// How many levels are quieter than the current one, `0` at [`LogLevel::min_level`]
+    pub fn levels_below_current(&self) -> usize {
+        (self.level_verbosity() - level_bounds::<L>().0).max(0) as usize
     }
-}
 
-#[cfg(test)]
-mod test {
-    use super::*;
+    /// If [`Verbosity::decrement`] would have no effect
+    ///
+    /// That is, the level is `off`, or [`LogLevel::min_level`] if higher, so more `-q` flags
+    /// won't remove output.
+    pub fn is_at_min(&self) -> bool {
+        self.level_verbosity() <= level_bounds::<L>().0
+    }
 
-    #[test]
-    fn verify_app() {
-        #[derive(Debug, clap::Parser)]
-        struct Cli {
-            #[command(flatten)]
-            verbose: Verbosity,
+    /// Show one more level, like another `--verbose`
+    ///
+    /// A pending `--quiet` is cancelled out first.  Once trace is reached, this does nothing.
+    pub fn increment(&mut self) {
+        self.increment_n(1);
+    }
+
+    /// Call [`Verbosity::increment`] `n` times
+    pub fn increment_n(&mut self, n: u8) {
+        for _ in 0..n {
+            if self.is_at_max() {
+                break;
+            }
+            if 0 < self.quiet {
+                self.quiet -= 1;
+            } else {
+                self.verbose = self.verbose.saturating_add(1);
+            }
+        }
+    }
+
+    /// Show one less level, like another `--quiet`
+    ///
+    /// A pending `--verbose` is cancelled out first.  Once off is reached, this does nothing.
+    pub fn decrement(&mut self) {
+        self.decrement_n(1);
+    }
+
+    /// Call [`Verbosity::decrement`] `n` times
+    pub fn decrement_n(&mut self, n: u8) {
+        for _ in 0..n {
+            if self.is_at_min() {
+                break;
+            }
+            if 0 < self.verbose {
+                self.verbose -= 1;
+            } else {
+                self.quiet = self.quiet.saturating_add(1);
+            }
         }
+    }
 
-        use clap::CommandFactory;
-        Cli::command().debug_assert()
+    /// Like [`Verbosity::increment`], returning `None` when already at trace
+    pub fn checked_increment(&self) -> Option<Self> {
+        if self.is_at_max() {
+            return None;
+        }
+        let mut verbosity = Verbosity::new(self.verbose, self.quiet);
+        verbosity.increment();
+        Some(verbosity)
+    }
+
+    /// Like [`Verbosity::decrement`], returning `None` when already off
+    pub fn checked_decrement(&self) -> Option<Self> {
+        if self.is_at_min() {
+            return None;
+        }
+        let mut verbosity = Verbosity::new(self.verbose, self.quiet);
+        verbosity.decrement();
+        Some(verbosity)
+    }
+
+    /// Add `n` to the `--verbose` count, stopping at the count that reaches trace
+    ///
+    /// Unlike [`Verbosity::increment_n`], the `--quiet` count is left alone.
+    pub fn saturating_add_verbose(&mut self, n: u8) {
+        let max = Self::from_verbosity(4).verbose.max(self.verbose);
+        self.verbose = self.verbose.saturating_add(n).min(max);
+    }
+
+    /// Add `n` to the `--quiet` count, stopping at the count that reaches off
+    ///
+    /// Unlike [`Verbosity::decrement_n`], the `--verbose` count is left alone.
+    pub fn saturating_add_quiet(&mut self, n: u8) {
+        let max = Self::from_verbosity(-1).quiet.max(self.quiet);
+        self.quiet = self.quiet.saturating_add(n).min(max);
+    }
+
+    /// Like [`Verbosity::saturating_add_verbose`], returning the result
+    pub fn with_saturating_add_verbose(&self, n: u8) -> Self {
+        let mut verbosity = Verbosity::new(self.verbose, self.quiet);
+        verbosity.saturating_add_verbose(n);
+        verbosity
+    }
+
+    /// Like [`Verbosity::saturating_add_quiet`], returning the result
+    pub fn with_saturating_add_quiet(&self, n: u8) -> Self {
+        let mut verbosity = Verbosity::new(self.verbose, self.quiet);
+        verbosity.saturating_add_quiet(n);
+        verbosity
+    }
+
+    /// If the user requested complete silence (i.e. not just no-logging).
+    ///
+    /// This is never the case when [`LogLevel::min_level`] is raised above off.
+    pub fn is_silent(&self) -> bool {
+        self.level_verbosity() == -1
+    }
+
+    /// Get the net verbosity, relative to [`ErrorLevel`]
+    ///
+    /// This is the default level of `L` shifted by the `--verbose` and `--quiet` counts and is
+    /// what the `log` and `tracing` levels are derived from, making it useful for integrating with
+    /// other logging backends:
+    ///
+    /// | Value | Level |
+    /// |-------|-------|
+    /// | `-1`  | off   |
+    /// | `0`   | error |
+    /// | `1`   | warn  |
+    /// | `2`   | info  |
+    /// | `3`   | debug |
+    /// | `4`   | trace |
+    ///
+    /// The value saturates at `-1` and `4`, so counts beyond what is needed to reach either end,
+    /// up to `u8::MAX`, are allowed.
+    pub fn as_i8(&self) -> i8 {
+        self.shifted(Self::default_verbosity())
+    }
+
+    /// [`Verbosity::as_i8`], relative to `default` instead of `L`'s default
+    fn shifted(&self, default: i8) -> i8 {
+        let verbose = self.verbose.min(L::max_verbose_steps()) as i32;
+        let quiet = self.quiet.min(L::max_quiet_steps()) as i32;
+        let step = Self::step_size() as i32;
+        let verbosity = default as i32 + (verbose - quiet) * step;
+        verbosity.clamp(-1, 4) as i8
+    }
+
+    /// Get the net verbosity, relative to [`ErrorLevel`]
+    #[deprecated(since = "2.1.0", note = "Use `Verbosity::as_i8`")]
+    pub fn verbosity(&self) -> i8 {
+        self.as_i8()
+    }
+
+    /// Get the name of the level, one of `off`, `error`, `warn`, `info`, `debug`, or `trace`
+    ///
+    /// This is the same as the [`Display`][fmt::Display] output and is bounded by
+    /// [`LogLevel::min_level`] and [`LogLevel::max_level`], like [`Verbosity::log_level`].  The
+    /// names can be customized with [`LogLevel::level_names`].
+    pub fn level_name(&self) -> &'static str {
+        L::level_names()[self.level_index()]
+    }
+
+    /// Describe how the level was resolved, for debugging configuration problems
+    ///
+    /// See [`Verbosity::context`] for the structured form.  Unlike [`Debug`][fmt::Debug], this
+    /// includes the default and resulting levels, like
+    /// `verbose=2 quiet=0 default=error effective=info [log feature] silent=false`.
+    pub fn to_diagnostic(&self) -> String {
+        let features = match (cfg!(feature = "log"), cfg!(feature = "tracing")) {
+            (true, true) => "[log and tracing features]",
+            (true, false) => "[log feature]",
+            (false, true) => "[tracing feature]",
+            (false, false) => "[no features]",
+        };
+        format!(
+            "verbose={} quiet={} default={} effective={} {features} silent={}",
+            self.verbose,
+            self.quiet,
+            L::label(),
+            self.level_name(),
+            self.is_silent()
+        )
+    }
+
+    /// Show the counts and level as `verbose=N quiet=M level=X`, for single-line structured logs
+    ///
+    /// This is shorter than [`Verbosity::to_diagnostic`] and doesn't allocate.
+    ///
+    /// ```rust
+    /// use clap_verbosity_flag::{InfoLevel, Verbosity};
+    ///
+    /// let verbosity = Verbosity::<InfoLevel>::new(1, 0);
+    /// assert_eq!(verbosity.format_args().to_string(), "verbose=1 quiet=0 level=debug");
+    /// ```
+    pub fn format_args(&self) -> impl fmt::Display + '_ {
+        FormatArgs(self)
+    }
+
+    /// Get the level as an index into [`LEVEL_NAMES`], from `0` (off) through `5` (trace)
+    ///
+    /// This is for looking up per-level values, like colors:
+    /// ```rust
+    /// use clap_verbosity_flag::{Verbosity, LEVEL_COUNT};
+    ///
+    /// const LEVEL_COLORS: [&str; LEVEL_COUNT] = ["", "red", "yellow", "green", "blue", "grey"];
+    ///
+    /// let verbosity = Verbosity::<clap_verbosity_flag::InfoLevel>::new(0, 0);
+    /// assert_eq!(LEVEL_COLORS[verbosity.level_index()], "green");
+    /// ```
+    pub fn level_index(&self) -> usize {
+        (self.level_verbosity() + 1) as usize
+    }
+
+    /// Read the verbosity from an environment variable
+    ///
+    /// The value is parsed like [`FromStr`][std::str::FromStr], as either a level name (`off`,
+    /// `error`, `warn`, `info`, `debug`, `trace`) or its [`Verbosity::as_i8`] (`-1` through `4`).
+    ///
+    /// If the variable is unset or can't be parsed, no flags are assumed.
+    pub fn from_env(var: &str) -> Self {
+        std::env::var(var)
+            .ok()
+            .and_then(|value| parse_level_value(&value))
+            .map(Self::from_verbosity)
+            .unwrap_or_else(|| Verbosity::new(0, 0))
+    }
+
+    /// Get the level as a `RUST_LOG` value, like `info` or `off`
+    ///
+    /// This lets subprocesses that use `env_logger` or `tracing_subscriber` inherit the verbosity.
+    pub fn to_env_logger_filter_string(&self) -> String {
+        level_name(self.level_verbosity()).to_owned()
+    }
+
+    /// Set `RUST_LOG` to [`Verbosity::to_env_logger_filter_string`]
+    ///
+    /// This changes the environment for the whole process, including subprocesses spawned later.
+    pub fn set_rust_log_env(&self) {
+        std::env::set_var("RUST_LOG", self.to_env_logger_filter_string());
+    }
+
+    /// Pass the level along to a subprocess through its environment
+    ///
+    /// `RUST_LOG` is set to [`Verbosity::to_env_logger_filter_string`], as is
+    /// [`LogLevel::env_var`], if any.  Unlike [`Verbosity::set_rust_log_env`], this process's
+    /// environment is left alone.
+    pub fn propagate_to_subprocess(&self, cmd: &mut std::process::Command) {
+        if let Some(var) = L::env_var() {
+            self.write_env_for_subprocess(cmd, var);
+        }
+        self.set_rust_log_for_subprocess(cmd);
+    }
+
+    /// Set `var` for a subprocess to the level name, like `debug`
+    ///
+    /// This is the same level as [`Verbosity::to_env_logger_filter_string`].  Read it back with
+    /// [`Verbosity::from_env_var`].
+    pub fn write_env_for_subprocess(&self, cmd: &mut std::process::Command, var: &str) {
+        cmd.env(var, self.to_env_logger_filter_string());
+    }
+
+    /// Set `RUST_LOG` for a subprocess, see [`Verbosity::write_env_for_subprocess`]
+    pub fn set_rust_log_for_subprocess(&self, cmd: &mut std::process::Command) {
+        self.write_env_for_subprocess(cmd, "RUST_LOG");
+    }
+
+    /// Get an `sh` command that reproduces this verbosity through the environment
+    ///
+    /// Without a `prefix`, this sets `RUST_LOG` to [`Verbosity::to_env_logger_filter_string`].
+    /// With one, it sets the counts read by [`Verbosity::with_env_prefix`].  The prefix isn't
+    /// quoted, so it should be a valid variable name.
+    ///
+    /// ```rust
+    /// use clap_verbosity_flag::{InfoLevel, Verbosity};
+    ///
+    /// let verbosity = Verbosity::<InfoLevel>::new(2, 0);
+    /// assert_eq!(verbosity.shell_export_string(None), "export RUST_LOG=trace");
+    /// assert_eq!(
+    ///     verbosity.shell_export_string(Some("MY_APP")),
+    ///     "export MY_APP_VERBOSE=2 MY_APP_QUIET=0"
+    /// );
+    /// ```
+    pub fn shell_export_string(&self, prefix: Option<&str>) -> String {
+        let assignments: Vec<_> = self
+            .export_vars(prefix)
+            .into_iter()
+            .map(|(var, value)| format!("{var}={value}"))
+            .collect();
+        format!("export {}", assignments.join(" "))
+    }
+
+    /// Get a PowerShell command that reproduces this verbosity through the environment
+    ///
+    /// This sets the same variables as [`Verbosity::shell_export_string`].
+    ///
+    /// ```rust
+    /// use clap_verbosity_flag::{InfoLevel, Verbosity};
+    ///
+    /// let verbosity = Verbosity::<InfoLevel>::new(0, 1);
+    /// assert_eq!(verbosity.powershell_export_string(None), "$env:RUST_LOG = \"warn\"");
+    /// ```
+    pub fn powershell_export_string(&self, prefix: Option<&str>) -> String {
+        let assignments: Vec<_> = self
+            .export_vars(prefix)
+            .into_iter()
+            .map(|(var, value)| format!("$env:{var} = \"{value}\""))
+            .collect();
+        assignments.join("; ")
+    }
+
+    /// Variables for [`Verbosity::shell_export_string`] and
+    /// [`Verbosity::powershell_export_string`]
+    fn export_vars(&self, prefix: Option<&str>) -> Vec<(String, String)> {
+        match prefix {
+            Some(prefix) => vec![
+                (format!("{prefix}_VERBOSE"), self.verbose.to_string()),
+                (format!("{prefix}_QUIET"), self.quiet.to_string()),
+            ],
+            None => vec![("RUST_LOG".to_owned(), self.to_env_logger_filter_string())],
+        }
+    }
+
+    /// Read a level set by [`Verbosity::write_env_for_subprocess`]
+    ///
+    /// This accepts the same values as [`FromStr`][std::str::FromStr], returning `None` if `var`
+    /// is unset or invalid.
+    pub fn from_env_var(var: &str) -> Option<Self> {
+        std::env::var(var).ok()?.parse().ok()
+    }
+
+    /// Read a level passed along by [`Verbosity::propagate_to_subprocess`]
+    ///
+    /// This is the same as [`Verbosity::from_env`].
+    pub fn from_subprocess_env(var: &str) -> Self {
+        Self::from_env(var)
+    }
+
+    /// Add the counts from `{prefix}_VERBOSE` and `{prefix}_QUIET`
+    ///
+    /// The counts are added to the existing ones, so apply this to the parsed flags to let
+    /// `--quiet` cancel out `{prefix}_VERBOSE` and vice versa:
+    ///
+    /// ```rust,no_run
+    /// # use clap::Parser;
+    /// # #[derive(Parser)]
+    /// # struct Cli {
+    /// #     #[command(flatten)]
+    /// #     verbose: clap_verbosity_flag::Verbosity,
+    /// # }
+    /// let cli = Cli::parse();
+    /// let verbose = cli.verbose.with_env_prefix("MY_APP");
+    /// ```
+    ///
+    /// Unset or invalid variables are ignored.
+    pub fn with_env_prefix(&self, prefix: &str) -> Self {
+        self.with_verbose_env(&format!("{prefix}_VERBOSE"))
+            .with_quiet_env(&format!("{prefix}_QUIET"))
+    }
+
+    /// Add the counts from [`LogLevel::verbose_env_var_name`] and
+    /// [`LogLevel::quiet_env_var_name`]
+    fn with_level_env(&self) -> Self {
+        let prefixed = |suffix| L::env_var_prefix().map(|prefix| format!("{prefix}_{suffix}"));
+        let verbose = L::verbose_env_var_name()
+            .map(str::to_owned)
+            .or_else(|| prefixed("VERBOSE"));
+        let quiet = L::quiet_env_var_name()
+            .map(str::to_owned)
+            .or_else(|| prefixed("QUIET"));
+        let verbosity = match verbose {
+            Some(var) => self.with_verbose_env(&var),
+            None => Verbosity::new(self.verbose, self.quiet),
+        };
+        match quiet {
+            Some(var) => verbosity.with_quiet_env(&var),
+            None => verbosity,
+        }
+    }
+
+    /// Add the count in `var` to the `--verbose` count
+    ///
+    /// Unset or invalid variables are ignored.
+    pub fn with_verbose_env(&self, var: &str) -> Self {
+        Verbosity::new(self.verbose.saturating_add(env_count(var)), self.quiet)
+    }
+
+    /// Add the count in `var` to the `--quiet` count
+    ///
+    /// Unset or invalid variables are ignored.
+    pub fn with_quiet_env(&self, var: &str) -> Self {
+        Verbosity::new(self.verbose, self.quiet.saturating_add(env_count(var)))
+    }
+
+    /// Create a verbosity instance from a net offset from `L`'s default
+    ///
+    /// Positive values are the `--verbose` count and negative values are the `--quiet` count.
+    /// Offsets beyond off or trace are saturated.
+    pub fn from_count(net: i8) -> Self {
+        let verbosity = (Self::default_verbosity() as i16 + net as i16).clamp(-1, 4);
+        Self::from_verbosity(verbosity as i8)
+    }
+
+    #[cfg(feature = "log")]
+    /// Create a verbosity instance that resolves to `level`
+    ///
+    /// `None` disables all output.
+    pub fn from_log_level(level: Option<log::Level>) -> Self {
+        Self::from_verbosity(level_value_log(level))
+    }
+
+    #[cfg(feature = "log")]
+    /// Restrict the resolved level to `min..=max`
+    ///
+    /// `None` is the quietest level.  If the level is already in range, the counts are left
+    /// unchanged.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, if `min` is more verbose than `max`
+    pub fn clamped(&self, min: Option<log::Level>, max: Option<log::Level>) -> Self {
+        let min = level_value_log(min);
+        let max = level_value_log(max);
+        debug_assert!(
+            min <= max,
+            "minimum level ({min}) must not be more verbose than the maximum level ({max})"
+        );
+        let verbosity = self.as_i8();
+        if verbosity < min {
+            Self::from_verbosity(min)
+        } else if max < verbosity {
+            Self::from_verbosity(max)
+        } else {
+            Verbosity::new(self.verbose, self.quiet)
+        }
+    }
+
+    #[cfg(feature = "log")]
+    /// Restrict the resolved level to `min..=max`, like [`Ord::clamp`]
+    ///
+    /// `None` is the quietest level.  Unlike [`Verbosity::clamped`], the counts are always
+    /// recomputed relative to `L`'s default.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, if `min` is more verbose than `max`.  Release builds resolve to `max`.
+    ///
+    /// ```rust
+    /// use clap_verbosity_flag::{InfoLevel, Verbosity};
+    ///
+    /// let verbosity = Verbosity::<InfoLevel>::new(3, 0);
+    /// let verbosity = verbosity.clamp_to_range(Some(log::Level::Warn), Some(log::Level::Debug));
+    /// assert_eq!(verbosity.log_level(), Some(log::Level::Debug));
+    /// assert_eq!(verbosity.verbose_count(), 1);
+    /// ```
+    pub fn clamp_to_range(&self, min: Option<log::Level>, max: Option<log::Level>) -> Self {
+        let min_value = level_value_log(min);
+        let max_value = level_value_log(max);
+        if max_value < min_value {
+            if cfg!(debug_assertions) {
+                panic!(
+                    "invalid range: minimum level ({min:?}) is more verbose than the maximum \
+                     level ({max:?})"
+                );
+            }
+            return Self::from_verbosity(max_value);
+        }
+        Self::from_verbosity(self.as_i8().clamp(min_value, max_value))
+    }
+
+    /// Combine with `other`, keeping the more verbose of the two
+    ///
+    /// The counts are recomputed relative to `L`'s default.
+    pub fn merge(&self, other: &Verbosity<L>) -> Verbosity<L> {
+        Self::from_verbosity(self.as_i8().max(other.as_i8()))
+    }
+
+    /// Combine with `other`, keeping the quieter of the two
+    ///
+    /// The counts are recomputed relative to `L`'s default.
+    pub fn merge_min(&self, other: &Verbosity<L>) -> Verbosity<L> {
+        Self::from_verbosity(self.as_i8().min(other.as_i8()))
+    }
+
+    /// How many levels more verbose than `other` this is
+    ///
+    /// Negative when this is quieter than `other`.
+    pub fn difference(&self, other: &Verbosity<L>) -> i8 {
+        self.as_i8() - other.as_i8()
+    }
+
+    /// How many levels more verbose than `L`'s default this is
+    pub fn diff_from_default(&self) -> i8 {
+        self.difference(&Verbosity::new(0, 0))
+    }
+
+    #[cfg(feature = "tracing")]
+    /// Create a verbosity instance that resolves to `filter`
+    pub fn from_tracing_level_filter(filter: LevelFilter) -> Self {
+        Self::from_verbosity(level_value_tracing(Some(filter)))
+    }
+
+    fn default_verbosity() -> i8 {
+        default_verbosity::<L>()
+    }
+
+    /// Pick the counts that shift `L`'s default to `verbosity`
+    ///
+    /// With a [`LogLevel::step_size`] above `1`, this may go past `verbosity`.
+    fn from_verbosity(verbosity: i8) -> Self {
+        let offset = verbosity as i16 - Self::default_verbosity() as i16;
+        let step = Self::step_size() as i16;
+        let count = ((offset.abs() + step - 1) / step).min(u8::MAX as i16) as u8;
+        if offset < 0 {
+            Verbosity::new(0, count)
+        } else {
+            Verbosity::new(count, 0)
+        }
+    }
+
+    /// Levels per occurrence, treating `0` as `1`
+    fn step_size() -> u8 {
+        L::step_size().max(1)
+    }
+}
+
+impl Verbosity {
+    #[cfg(feature = "log")]
+    /// Every level that can be reached, from quietest (`None`) to most verbose
+    pub fn levels() -> impl Iterator<Item = Option<log::Level>> {
+        (-1..=4).map(level_enum_log)
+    }
+
+    #[cfg(feature = "tracing")]
+    /// Every level filter that can be reached, from quietest (`OFF`) to most verbose
+    pub fn tracing_levels() -> impl Iterator<Item = LevelFilter> {
+        (-1..=4).map(level_enum_tracing)
+    }
+}
+
+#[cfg(feature = "log")]
+impl<L: LogLevel> From<log::Level> for Verbosity<L> {
+    fn from(level: log::Level) -> Self {
+        Self::from_log_level(Some(level))
+    }
+}
+
+#[cfg(feature = "log")]
+impl<L: LogLevel> From<log::LevelFilter> for Verbosity<L> {
+    fn from(filter: log::LevelFilter) -> Self {
+        Self::from_log_level(filter.to_level())
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl<L: LogLevel> From<tracing_core::Level> for Verbosity<L> {
+    fn from(level: tracing_core::Level) -> Self {
+        Self::from_tracing_level_filter(LevelFilter::from_level(level))
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl<L: LogLevel> From<LevelFilter> for Verbosity<L> {
+    fn from(filter: LevelFilter) -> Self {
+        Self::from_tracing_level_filter(filter)
+    }
+}
+
+/// Fails with [`SilentError`] when all output is disabled
+#[cfg(feature = "log")]
+impl<L: LogLevel> TryFrom<Verbosity<L>> for log::Level {
+    type Error = SilentError;
+
+    fn try_from(verbosity: Verbosity<L>) -> Result<Self, SilentError> {
+        verbosity.log_level().ok_or(SilentError(()))
+    }
+}
+
+#[cfg(feature = "log")]
+impl<L: LogLevel> From<Verbosity<L>> for Option<log::Level> {
+    fn from(verbosity: Verbosity<L>) -> Self {
+        verbosity.log_level()
+    }
+}
+
+#[cfg(feature = "log")]
+impl<L: LogLevel> From<Verbosity<L>> for log::LevelFilter {
+    fn from(verbosity: Verbosity<L>) -> Self {
+        verbosity.log_level_filter()
+    }
+}
+
+/// Converting back with [`Verbosity::from`] resolves to the same level but not necessarily the
+/// same `--verbose` and `--quiet` counts
+#[cfg(feature = "tracing")]
+impl<L: LogLevel> From<Verbosity<L>> for LevelFilter {
+    fn from(verbosity: Verbosity<L>) -> Self {
+        verbosity.tracing_level_filter()
+    }
+}
+
+/// Fails with [`SilentError`] when all output is disabled
+#[cfg(feature = "tracing")]
+impl<L: LogLevel> TryFrom<Verbosity<L>> for tracing_core::Level {
+    type Error = SilentError;
+
+    fn try_from(verbosity: Verbosity<L>) -> Result<Self, SilentError> {
+        verbosity
+            .tracing_level_filter()
+            .into_level()
+            .ok_or(SilentError(()))
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl<L: LogLevel> From<Verbosity<L>> for Option<tracing_core::Level> {
+    fn from(verbosity: Verbosity<L>) -> Self {
+        verbosity.tracing_level_filter().into_level()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, L: LogLevel> serde::Deserialize<'de> for Verbosity<L> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct Raw {
+            #[serde(default)]
+            verbose: u8,
+            #[serde(default)]
+            quiet: u8,
+        }
+
+        let raw = Raw::deserialize(deserializer)?;
+        if L::verbose_conflicts_with_quiet() && raw.verbose != 0 && raw.quiet != 0 {
+            return Err(serde::de::Error::custom(format!(
+                "`verbose` ({}) and `quiet` ({}) cannot both be set",
+                raw.verbose, raw.quiet
+            )));
+        }
+        Ok(Verbosity::new(raw.verbose, raw.quiet))
+    }
+}
+
+/// Counts are limited to `0..=8` as larger counts behave the same
+#[cfg(feature = "arbitrary")]
+impl<'a, L: LogLevel + 'static> arbitrary::Arbitrary<'a> for Verbosity<L> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Verbosity::new(
+            u.int_in_range(0..=8)?,
+            u.int_in_range(0..=8)?,
+        ))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (2, Some(2))
+    }
+}
+
+/// Generate one [`Verbosity`] for each level, from off through trace
+#[cfg(feature = "proptest")]
+pub fn verbosity_strategy<L: LogLevel + fmt::Debug + 'static>(
+) -> impl proptest::strategy::Strategy<Value = Verbosity<L>> {
+    use proptest::strategy::Strategy as _;
+
+    proptest::sample::select(vec![-1, 0, 1, 2, 3, 4]).prop_map(Verbosity::from_verbosity)
+}
+
+#[cfg(feature = "log")]
+/// Map a log level to its verbosity, the inverse of [`level_enum_log`]
+///
+/// | Level                     | Verbosity |
+/// |---------------------------|-----------|
+/// | `None`                    | `-1`      |
+/// | `Some(log::Level::Error)` | `0`       |
+/// | `Some(log::Level::Warn)`  | `1`       |
+/// | `Some(log::Level::Info)`  | `2`       |
+/// | `Some(log::Level::Debug)` | `3`       |
+/// | `Some(log::Level::Trace)` | `4`       |
+#[inline]
+#[must_use]
+pub fn level_value_log(level: Option<log::Level>) -> i8 {
+    match level {
+        None => -1,
+        Some(log::Level::Error) => 0,
+        Some(log::Level::Warn) => 1,
+        Some(log::Level::Info) => 2,
+        Some(log::Level::Debug) => 3,
+        Some(log::Level::Trace) => 4,
+    }
+}
+
+#[cfg(feature = "tracing")]
+/// Map a tracing level filter to its verbosity, the inverse of [`level_enum_tracing`]
+///
+/// | Level filter                | Verbosity |
+/// |-----------------------------|-----------|
+/// | `None`, `Some(OFF)`         | `-1`      |
+/// | `Some(ERROR)`               | `0`       |
+/// | `Some(WARN)`                | `1`       |
+/// | `Some(INFO)`                | `2`       |
+/// | `Some(DEBUG)`               | `3`       |
+/// | `Some(TRACE)`               | `4`       |
+#[inline]
+#[must_use]
+pub fn level_value_tracing(level: Option<LevelFilter>) -> i8 {
+    match level {
+        Some(LevelFilter::ERROR) => 0,
+        Some(LevelFilter::WARN) => 1,
+        Some(LevelFilter::INFO) => 2,
+        Some(LevelFilter::DEBUG) => 3,
+        Some(LevelFilter::TRACE) => 4,
+        _ => -1,
+    }
+}
+
+fn level_value_name(name: &str) -> Option<i8> {
+    let verbosity = match name.to_ascii_lowercase().as_str() {
+        "off" => -1,
+        "error" => 0,
+        "warn" => 1,
+        "info" => 2,
+        "debug" => 3,
+        "trace" => 4,
+        _ => return None,
+    };
+    Some(verbosity)
+}
+
+/// Parse a level name or [`Verbosity::as_i8`] value, see [`ParseLevelError::valid_values`]
+fn parse_level_value(input: &str) -> Option<i8> {
+    let trimmed = input.trim();
+    level_value_name(trimmed).or_else(|| match trimmed.parse::<i8>() {
+        Ok(verbosity @ -1..=4) => Some(verbosity),
+        _ => None,
+    })
+}
+
+fn default_verbosity<L: LogLevel + ?Sized>() -> i8 {
+    filter_string_level(L::default_filter_string()).unwrap_or_else(backend_default::<L>)
+}
+
+/// `L`'s default from its backend hooks, as a [`Verbosity::as_i8`] value
+///
+/// When both backends are enabled, the `log` default is used.  Without either,
+/// `LogLevel::default_known_level` is used.
+#[cfg(feature = "log")]
+fn backend_default<L: LogLevel + ?Sized>() -> i8 {
+    level_value_log(L::default_log())
+}
+
+#[cfg(all(feature = "tracing", not(feature = "log")))]
+fn backend_default<L: LogLevel + ?Sized>() -> i8 {
+    level_value_tracing(L::default_tracing())
+}
+
+#[cfg(not(any(feature = "log", feature = "tracing")))]
+fn backend_default<L: LogLevel + ?Sized>() -> i8 {
+    L::default_known_level().as_i8()
+}
+
+/// The quietest and most verbose levels allowed by `L`, as [`Verbosity::as_i8`] values
+///
+/// When both backends are enabled, the `log` bounds are used.
+#[cfg(feature = "log")]
+fn level_bounds<L: LogLevel + ?Sized>() -> (i8, i8) {
+    (
+        level_value_log(L::min_level()),
+        level_value_log(L::max_level()),
+    )
+}
+
+#[cfg(all(feature = "tracing", not(feature = "log")))]
+fn level_bounds<L: LogLevel + ?Sized>() -> (i8, i8) {
+    (
+        level_value_tracing(Some(L::min_tracing_level())),
+        level_value_tracing(Some(L::max_tracing_level())),
+    )
+}
+
+#[cfg(not(any(feature = "log", feature = "tracing")))]
+fn level_bounds<L: LogLevel + ?Sized>() -> (i8, i8) {
+    (L::min_known_level().as_i8(), L::max_known_level().as_i8())
+}
+
+/// The global level in an `env_logger`-style filter, like `warn` in `my_crate=info,warn`
+///
+/// When given more than once, the last one wins.
+fn filter_string_level(filter: &str) -> Option<i8> {
+    filter
+        .rsplit(',')
+        .map(str::trim)
+        .filter(|directive| !directive.contains('='))
+        .find_map(level_value_name)
+}
+
+fn env_count(var: &str) -> u8 {
+    std::env::var(var)
+        .ok()
+        .and_then(|value| value.trim().parse().ok())
+        .unwrap_or(0)
+}
+
+/// The name of every level, indexed by [`Verbosity::level_index`]
+pub const LEVEL_NAMES: [&str; LEVEL_COUNT] = ["off", "error", "warn", "info", "debug", "trace"];
+
+/// The number of levels, from off through trace
+pub const LEVEL_COUNT: usize = 6;
+
+fn level_name(verbosity: i8) -> &'static str {
+    LEVEL_NAMES[(verbosity.clamp(-1, 4) + 1) as usize]
+}
+
+#[cfg(feature = "log")]
+/// Map a verbosity, like [`Verbosity::as_i8`], to a log level
+///
+/// | Verbosity    | Level                     |
+/// |--------------|---------------------------|
+/// | `-1` or less | `None`                    |
+/// | `0`          | `Some(log::Level::Error)` |
+/// | `1`          | `Some(log::Level::Warn)`  |
+/// | `2`          | `Some(log::Level::Info)`  |
+/// | `3`          | `Some(log::Level::Debug)` |
+/// | `4` or more  | `Some(log::Level::Trace)` |
+#[inline]
+#[must_use]
+pub fn level_enum_log(verbosity: i8) -> Option<log::Level> {
+    match verbosity {
+        i8::MIN..=-1 => None,
+        0 => Some(log::Level::Error),
+        1 => Some(log::Level::Warn),
+        2 => Some(log::Level::Info),
+        3 => Some(log::Level::Debug),
+        4.. => Some(log::Level::Trace),
+    }
+}
+
+#[cfg(feature = "tracing")]
+/// Map a verbosity, like [`Verbosity::as_i8`], to a tracing level filter
+///
+/// | Verbosity    | Level filter |
+/// |--------------|--------------|
+/// | `-1` or less | `OFF`        |
+/// | `0`          | `ERROR`      |
+/// | `1`          | `WARN`       |
+/// | `2`          | `INFO`       |
+/// | `3`          | `DEBUG`      |
+/// | `4` or more  | `TRACE`      |
+#[inline]
+#[must_use]
+pub fn level_enum_tracing(verbosity: i8) -> LevelFilter {
+    match verbosity {
+        i8::MIN..=-1 => LevelFilter::OFF,
+        0 => LevelFilter::ERROR,
+        1 => LevelFilter::WARN,
+        2 => LevelFilter::INFO,
+        3 => LevelFilter::DEBUG,
+        4.. => LevelFilter::TRACE,
+    }
+}
+
+use std::fmt;
+
+/// Shows the resolved level, like `info` or `off`
+///
+/// See [`Verbosity::as_i8`] for the numeric form.
+impl<L: LogLevel> fmt::Display for Verbosity<L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.level_name())
+    }
+}
+
+/// See [`Verbosity::format_args`]
+struct FormatArgs<'v, L: LogLevel>(&'v Verbosity<L>);
+
+impl<L: LogLevel> fmt::Display for FormatArgs<'_, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "verbose={} quiet={} level={}",
+            self.0.verbose,
+            self.0.quiet,
+            self.0.level_name()
+        )
+    }
+}
+
+/// No `--verbose` or `--quiet`, leaving `L`'s default level
+impl<L: LogLevel + Default> Default for Verbosity<L> {
+    fn default() -> Self {
+        Self::new(0, 0)
+    }
+}
+
+/// Verbosities compare by their [`Verbosity::as_i8`], not their raw counts
+impl<L: LogLevel> PartialEq for Verbosity<L> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_i8() == other.as_i8()
+    }
+}
+
+impl<L: LogLevel> Eq for Verbosity<L> {}
+
+/// Consistent with [`PartialEq`], only [`Verbosity::as_i8`] is hashed
+impl<L: LogLevel> std::hash::Hash for Verbosity<L> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.as_i8().hash(state);
+    }
+}
+
+impl<L: LogLevel> PartialOrd for Verbosity<L> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<L: LogLevel> Ord for Verbosity<L> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.as_i8().cmp(&other.as_i8())
+    }
+}
+
+/// Combine the `--verbose` and `--quiet` counts, saturating at `u8::MAX`
+impl<L: LogLevel> std::ops::Add for Verbosity<L> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Verbosity::new(
+            self.verbose.saturating_add(rhs.verbose),
+            self.quiet.saturating_add(rhs.quiet),
+        )
+    }
+}
+
+impl<L: LogLevel> std::ops::AddAssign for Verbosity<L> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.verbose = self.verbose.saturating_add(rhs.verbose);
+        self.quiet = self.quiet.saturating_add(rhs.quiet);
+    }
+}
+
+/// Remove `--verbose` and `--quiet` counts, saturating at `0`
+///
+/// Because of saturation, this is not always the inverse of [`Add`][std::ops::Add]:
+/// `(a + b) - b` may not equal `a`.
+impl<L: LogLevel> std::ops::Sub for Verbosity<L> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Verbosity::new(
+            self.verbose.saturating_sub(rhs.verbose),
+            self.quiet.saturating_sub(rhs.quiet),
+        )
+    }
+}
+
+impl<L: LogLevel> std::ops::SubAssign for Verbosity<L> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.verbose = self.verbose.saturating_sub(rhs.verbose);
+        self.quiet = self.quiet.saturating_sub(rhs.quiet);
+    }
+}
+
+/// Add up the `--verbose` and `--quiet` counts, see [`Add`][std::ops::Add]
+///
+/// An empty iterator gives no flags.  To take the most verbose instead, see [`collect_max`].
+impl<L: LogLevel> FromIterator<Verbosity<L>> for Verbosity<L> {
+    fn from_iter<I: IntoIterator<Item = Verbosity<L>>>(iter: I) -> Self {
+        let mut verbosity = Verbosity::new(0, 0);
+        verbosity.extend(iter);
+        verbosity
+    }
+}
+
+/// Add the `--verbose` and `--quiet` counts, see [`AddAssign`][std::ops::AddAssign]
+impl<L: LogLevel> Extend<Verbosity<L>> for Verbosity<L> {
+    fn extend<I: IntoIterator<Item = Verbosity<L>>>(&mut self, iter: I) {
+        for verbosity in iter {
+            *self += verbosity;
+        }
+    }
+}
+
+/// Take the most verbose, see [`BitOr`][std::ops::BitOr]
+///
+/// An empty iterator gives no flags.
+pub fn collect_max<L: LogLevel>(iter: impl IntoIterator<Item = Verbosity<L>>) -> Verbosity<L> {
+    iter.into_iter()
+        .reduce(|max, verbosity| max | verbosity)
+        .unwrap_or_else(|| Verbosity::new(0, 0))
+}
+
+/// Swap the `--verbose` and `--quiet` counts, the same distance from `L`'s default in the other
+/// direction
+///
+/// `v + (-v)` resolves to the default level.  When both counts are set, both are swapped, so
+/// `-Verbosity::new(1, 3)` is `Verbosity::new(3, 1)`.
+impl<L: LogLevel> std::ops::Neg for Verbosity<L> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Verbosity::new(self.quiet, self.verbose)
+    }
+}
+
+/// Take the more verbose of the two, like merging what either would log
+///
+/// One of the two is returned as-is, rather than blending their counts, preferring `self` when
+/// both resolve to the same level.
+impl<L: LogLevel> std::ops::BitOr for Verbosity<L> {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        if self.as_i8() < rhs.as_i8() {
+            rhs
+        } else {
+            self
+        }
+    }
+}
+
+impl<L: LogLevel> std::ops::BitOrAssign for Verbosity<L> {
+    fn bitor_assign(&mut self, rhs: Self) {
+        if self.as_i8() < rhs.as_i8() {
+            *self = rhs;
+        }
+    }
+}
+
+/// Take the quieter of the two, like keeping only what both would log
+///
+/// One of the two is returned as-is, rather than blending their counts, preferring `self` when
+/// both resolve to the same level.
+impl<L: LogLevel> std::ops::BitAnd for Verbosity<L> {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        if rhs.as_i8() < self.as_i8() {
+            rhs
+        } else {
+            self
+        }
+    }
+}
+
+impl<L: LogLevel> std::ops::BitAndAssign for Verbosity<L> {
+    fn bitand_assign(&mut self, rhs: Self) {
+        if rhs.as_i8() < self.as_i8() {
+            *self = rhs;
+        }
+    }
+}
+
+/// Parse a level name (`off`, `error`, `warn`, `info`, `debug`, `trace`) or its
+/// [`Verbosity::as_i8`] (`-1` through `4`)
+impl<L: LogLevel> TryFrom<&str> for Verbosity<L> {
+    type Error = ParseLevelError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        match parse_level_value(input) {
+            Some(verbosity) => Ok(Self::from_verbosity(verbosity)),
+            None => Err(ParseLevelError {
+                input: input.to_owned(),
+            }),
+        }
+    }
+}
+
+impl<L: LogLevel> std::str::FromStr for Verbosity<L> {
+    type Err = ParseLevelError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::try_from(input)
+    }
+}
+
+/// The input was not a recognized level
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseLevelError {
+    input: String,
+}
+
+impl ParseLevelError {
+    /// The input that failed to parse
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// All accepted inputs, ignoring case for level names
+    pub fn valid_values() -> &'static [&'static str] {
+        &[
+            "off", "error", "warn", "info", "debug", "trace", "-1", "0", "1", "2", "3", "4",
+        ]
+    }
+}
+
+impl fmt::Display for ParseLevelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid level `{}`, expected one of ", self.input)?;
+        for (i, value) in Self::valid_values().iter().enumerate() {
+            if i != 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "`{value}`")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseLevelError {}
+
+/// The verbosity disables all output, so there is no level
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SilentError(());
+
+impl fmt::Display for SilentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("all output is disabled")
+    }
+}
+
+impl std::error::Error for SilentError {}
+
+pub trait LogLevel {
+    #[cfg(feature = "log")]
+    fn default_log() -> Option<log::Level>;
+    #[cfg(feature = "tracing")]
+    fn default_tracing() -> Option<LevelFilter>;
+
+    /// The default level without the `log` and `tracing` features
+    ///
+    /// The built-in levels, [`verbosity_level!`], and [`impl_log_level!`] match their other
+    /// defaults.  Otherwise, this is [`KnownLevel::Error`], like [`ErrorLevel`].
+    #[cfg(not(any(feature = "log", feature = "tracing")))]
+    fn default_known_level() -> KnownLevel {
+        KnownLevel::Error
+    }
+
+    fn verbose_help() -> Option<&'static str> {
+        Some("More output per occurrence")
+    }
+
+    fn verbose_long_help() -> Option<&'static str> {
+        None
+    }
+
+    fn quiet_help() -> Option<&'static str> {
+        Some("Less output per occurrence")
+    }
+
+    fn quiet_long_help() -> Option<&'static str> {
+        None
+    }
+
+    /// Long flag name for increasing verbosity, without the leading `--`
+    fn verbose_long_name() -> &'static str {
+        "verbose"
+    }
+
+    /// Long flag name for decreasing verbosity, without the leading `--`
+    fn quiet_long_name() -> &'static str {
+        "quiet"
+    }
+
+    /// Short flag for increasing verbosity, `None` to only offer the long flag
+    fn verbose_short_char() -> Option<char> {
+        Some('v')
+    }
+
+    /// Short flag for decreasing verbosity, `None` to only offer the long flag
+    fn quiet_short_char() -> Option<char> {
+        Some('q')
+    }
+
+    /// The most verbose level reachable through `--verbose`
+    ///
+    /// When lowering this, consider mentioning it in [`LogLevel::verbose_help`].
+    #[cfg(feature = "log")]
+    fn max_level() -> Option<log::Level> {
+        Some(log::Level::Trace)
+    }
+
+    /// The most verbose level reachable through `--verbose`
+    ///
+    /// When lowering this, consider mentioning it in [`LogLevel::verbose_help`].
+    #[cfg(feature = "tracing")]
+    fn max_tracing_level() -> LevelFilter {
+        LevelFilter::TRACE
+    }
+
+    /// The most verbose level reachable through `--verbose`, without the `log` and `tracing`
+    /// features
+    ///
+    /// When lowering this, consider mentioning it in [`LogLevel::verbose_help`].
+    #[cfg(not(any(feature = "log", feature = "tracing")))]
+    fn max_known_level() -> KnownLevel {
+        KnownLevel::Trace
+    }
+
+    /// The quietest level reachable through `--quiet`
+    ///
+    /// When raising this, consider mentioning it in [`LogLevel::quiet_help`].
+    #[cfg(feature = "log")]
+    fn min_level() -> Option<log::Level> {
+        None
+    }
+
+    /// The quietest level reachable through `--quiet`
+    ///
+    /// When raising this, consider mentioning it in [`LogLevel::quiet_help`].
+    #[cfg(feature = "tracing")]
+    fn min_tracing_level() -> LevelFilter {
+        LevelFilter::OFF
+    }
+
+    /// The quietest level reachable through `--quiet`, without the `log` and `tracing` features
+    ///
+    /// When raising this, consider mentioning it in [`LogLevel::quiet_help`].
+    #[cfg(not(any(feature = "log", feature = "tracing")))]
+    fn min_known_level() -> KnownLevel {
+        KnownLevel::Off
+    }
+
+    /// Additional long names for `--verbose`, like `verbosity`
+    fn verbose_aliases() -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Additional long names for `--quiet`, like `silent`
+    fn quiet_aliases() -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Environment variable whose count is added to `--verbose` when parsing [`Verbosity`]
+    ///
+    /// This also applies to the wrappers, like [`VerbosityPermissive`] and [`LogLevelArg`].
+    /// Unset or invalid values are ignored.  Defaults to `{prefix}_VERBOSE` for
+    /// [`LogLevel::env_var_prefix`].
+    fn verbose_env_var_name() -> Option<&'static str> {
+        None
+    }
+
+    /// Environment variable whose count is added to `--quiet` when parsing [`Verbosity`]
+    ///
+    /// This also applies to the wrappers, like [`VerbosityPermissive`] and [`LogLevelArg`].
+    /// Unset or invalid values are ignored.  Defaults to `{prefix}_QUIET` for
+    /// [`LogLevel::env_var_prefix`].
+    fn quiet_env_var_name() -> Option<&'static str> {
+        None
+    }
+
+    /// Prefix for [`LogLevel::verbose_env_var_name`] and [`LogLevel::quiet_env_var_name`], like
+    /// [`Verbosity::with_env_prefix`]
+    fn env_var_prefix() -> Option<&'static str> {
+        None
+    }
+
+    /// Environment variable, besides `RUST_LOG`, set by [`Verbosity::propagate_to_subprocess`]
+    fn env_var() -> Option<&'static str> {
+        None
+    }
+
+    /// Name of the default level, like `info` or `off`
+    fn label() -> &'static str {
+        Self::level_names()[(default_verbosity::<Self>() + 1) as usize]
+    }
+
+    /// An `env_logger`-style filter, like `my_crate=info,warn`, whose global level is used as the
+    /// default
+    ///
+    /// When empty or without a global level, [`LogLevel::default_log`] is used.
+    fn default_filter_string() -> &'static str {
+        ""
+    }
+
+    /// Names for each level, from off through trace, like for [`Verbosity`]'s
+    /// [`Display`][fmt::Display]
+    ///
+    /// These are also suggested by shell completions and accepted by [`LogLevelArg`], alongside
+    /// the standard names.
+    fn level_names() -> &'static [&'static str; LEVEL_COUNT] {
+        &LEVEL_NAMES
+    }
+
+    /// Level names suggested by shell completions, like for [`LogLevelArg`]'s `--log-level`
+    ///
+    /// All level names are still accepted.
+    fn completion_values() -> &'static [&'static str] {
+        &Self::level_names()[1..]
+    }
+
+    /// How many levels each occurrence of `--verbose` or `--quiet` moves
+    ///
+    /// `0` is treated as `1`.
+    fn step_size() -> u8 {
+        1
+    }
+
+    /// The most occurrences of `--verbose` that are counted
+    ///
+    /// When lowering this, consider mentioning it in [`LogLevel::verbose_help`].
+    fn max_verbose_steps() -> u8 {
+        u8::MAX
+    }
+
+    /// The most occurrences of `--quiet` that are counted
+    ///
+    /// When lowering this, consider mentioning it in [`LogLevel::quiet_help`].
+    fn max_quiet_steps() -> u8 {
+        u8::MAX
+    }
+
+    /// Whether `--verbose` and `--quiet` conflict
+    ///
+    /// When `false`, both may be passed and offset each other, like with [`VerbosityPermissive`].
+    fn verbose_conflicts_with_quiet() -> bool {
+        true
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default)]
+pub struct NoneLevel;
+
+impl LogLevel for NoneLevel {
+    #[cfg(feature = "log")]
+    fn default_log() -> Option<log::Level> {
+        None
+    }
+
+    #[cfg(feature = "tracing")]
+    fn default_tracing() -> Option<LevelFilter> {
+        None
+    }
+
+    #[cfg(not(any(feature = "log", feature = "tracing")))]
+    fn default_known_level() -> KnownLevel {
+        KnownLevel::Off
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default)]
+pub struct ErrorLevel;
+
+impl LogLevel for ErrorLevel {
+    #[cfg(feature = "log")]
+    fn default_log() -> Option<log::Level> {
+        Some(log::Level::Error)
+    }
+
+    #[cfg(feature = "tracing")]
+    fn default_tracing() -> Option<LevelFilter> {
+        Some(LevelFilter::ERROR)
+    }
+
+    #[cfg(not(any(feature = "log", feature = "tracing")))]
+    fn default_known_level() -> KnownLevel {
+        KnownLevel::Error
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default)]
+pub struct WarnLevel;
+
+impl LogLevel for WarnLevel {
+    #[cfg(feature = "log")]
+    fn default_log() -> Option<log::Level> {
+        Some(log::Level::Warn)
+    }
+
+    #[cfg(feature = "tracing")]
+    fn default_tracing() -> Option<LevelFilter> {
+        Some(LevelFilter::WARN)
+    }
+
+    #[cfg(not(any(feature = "log", feature = "tracing")))]
+    fn default_known_level() -> KnownLevel {
+        KnownLevel::Warn
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default)]
+pub struct InfoLevel;
+
+impl LogLevel for InfoLevel {
+    #[cfg(feature = "log")]
+    fn default_log() -> Option<log::Level> {
+        Some(log::Level::Info)
+    }
+    #[cfg(feature = "tracing")]
+    fn default_tracing() -> Option<LevelFilter> {
+        Some(LevelFilter::INFO)
+    }
+
+    #[cfg(not(any(feature = "log", feature = "tracing")))]
+    fn default_known_level() -> KnownLevel {
+        KnownLevel::Info
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default)]
+pub struct DebugLevel;
+
+impl LogLevel for DebugLevel {
+    #[cfg(feature = "log")]
+    fn default_log() -> Option<log::Level> {
+        Some(log::Level::Debug)
+    }
+
+    #[cfg(feature = "tracing")]
+    fn default_tracing() -> Option<LevelFilter> {
+        Some(LevelFilter::DEBUG)
+    }
+
+    #[cfg(not(any(feature = "log", feature = "tracing")))]
+    fn default_known_level() -> KnownLevel {
+        KnownLevel::Debug
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default)]
+pub struct TraceLevel;
+
+impl LogLevel for TraceLevel {
+    #[cfg(feature = "log")]
+    fn default_log() -> Option<log::Level> {
+        Some(log::Level::Trace)
+    }
+
+    #[cfg(feature = "tracing")]
+    fn default_tracing() -> Option<LevelFilter> {
+        Some(LevelFilter::TRACE)
+    }
+
+    #[cfg(not(any(feature = "log", feature = "tracing")))]
+    fn default_known_level() -> KnownLevel {
+        KnownLevel::Trace
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn verify_app() {
+        #[derive(Debug, clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            verbose: Verbosity,
+        }
+
+        use clap::CommandFactory;
+        Cli::command().debug_assert()
+    }
+
+    #[test]
+    fn verify_app_debug_level() {
+        #[derive(Debug, clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            verbose: Verbosity<DebugLevel>,
+        }
+
+        use clap::CommandFactory;
+        Cli::command().debug_assert()
+    }
+
+    #[test]
+    #[cfg(feature = "log")]
+    fn debug_level_quiet_steps() {
+        let levels: Vec<_> = (0..=5)
+            .map(|quiet| Verbosity::<DebugLevel>::new(0, quiet).log_level())
+            .collect();
+        assert_eq!(
+            levels,
+            [
+                Some(log::Level::Debug),
+                Some(log::Level::Info),
+                Some(log::Level::Warn),
+                Some(log::Level::Error),
+                None,
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn verify_app_trace_level() {
+        #[derive(Debug, clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            verbose: Verbosity<TraceLevel>,
+        }
+
+        use clap::CommandFactory;
+        Cli::command().debug_assert()
+    }
+
+    #[test]
+    #[cfg(feature = "log")]
+    fn trace_level_quiet_steps() {
+        let levels: Vec<_> = (0..=6)
+            .map(|quiet| Verbosity::<TraceLevel>::new(0, quiet).log_level())
+            .collect();
+        assert_eq!(
+            levels,
+            [
+                Some(log::Level::Trace),
+                Some(log::Level::Debug),
+                Some(log::Level::Info),
+                Some(log::Level::Warn),
+                Some(log::Level::Error),
+                None,
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "tracing")]
+    fn trace_level_quiet_steps_tracing() {
+        let levels: Vec<_> = (0..=5)
+            .map(|quiet| Verbosity::<TraceLevel>::new(0, quiet).tracing_level_filter())
+            .collect();
+        assert_eq!(
+            levels,
+            [
+                LevelFilter::TRACE,
+                LevelFilter::DEBUG,
+                LevelFilter::INFO,
+                LevelFilter::WARN,
+                LevelFilter::ERROR,
+                LevelFilter::OFF,
+            ]
+        );
+    }
+
+    #[test]
+    fn verify_app_none_level() {
+        #[derive(Debug, clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            verbose: Verbosity<NoneLevel>,
+        }
+
+        use clap::CommandFactory;
+        Cli::command().debug_assert()
+    }
+
+    #[test]
+    fn none_level_is_silent_by_default() {
+        #[cfg(feature = "log")]
+        assert!(NoneLevel::default_log().is_none());
+        #[cfg(feature = "tracing")]
+        assert!(NoneLevel::default_tracing().is_none());
+        assert!(Verbosity::<NoneLevel>::new(0, 0).is_silent());
+        assert!(!Verbosity::<NoneLevel>::new(1, 0).is_silent());
+    }
+
+    #[test]
+    #[cfg(feature = "log")]
+    fn none_level_verbose_steps() {
+        let levels: Vec<_> = (0..=6)
+            .map(|verbose| Verbosity::<NoneLevel>::new(verbose, 0).log_level())
+            .collect();
+        assert_eq!(
+            levels,
+            [
+                None,
+                Some(log::Level::Error),
+                Some(log::Level::Warn),
+                Some(log::Level::Info),
+                Some(log::Level::Debug),
+                Some(log::Level::Trace),
+                Some(log::Level::Trace),
+            ]
+        );
+    }
+
+    #[test]
+    fn verbosity_boundaries() {
+        assert_eq!(Verbosity::<ErrorLevel>::new(0, 0).as_i8(), 0);
+        assert_eq!(Verbosity::<ErrorLevel>::new(0, 1).as_i8(), -1);
+        assert_eq!(Verbosity::<ErrorLevel>::new(0, 2).as_i8(), -1);
+        assert_eq!(Verbosity::<ErrorLevel>::new(4, 0).as_i8(), 4);
+        assert_eq!(Verbosity::<ErrorLevel>::new(5, 0).as_i8(), 4);
+        assert_eq!(Verbosity::<NoneLevel>::new(0, 0).as_i8(), -1);
+        assert_eq!(Verbosity::<NoneLevel>::new(5, 0).as_i8(), 4);
+        assert_eq!(Verbosity::<InfoLevel>::new(0, 3).as_i8(), -1);
+        assert_eq!(Verbosity::<TraceLevel>::new(0, 0).as_i8(), 4);
+        assert_eq!(Verbosity::<TraceLevel>::new(0, 5).as_i8(), -1);
+    }
+
+    #[test]
+    fn default() {
+        #[derive(Default)]
+        struct Config {
+            verbose: Verbosity<InfoLevel>,
+        }
+
+        let config = Config::default();
+        assert_eq!(config.verbose.verbose_count(), 0);
+        assert_eq!(config.verbose.quiet_count(), 0);
+        #[cfg(feature = "log")]
+        assert_eq!(config.verbose.log_level(), Some(log::Level::Info));
+        assert_eq!(Verbosity::<ErrorLevel>::default(), Verbosity::new(0, 0));
+    }
+
+    #[test]
+    fn aliases() {
+        #[derive(Copy, Clone, Debug, Default)]
+        struct AliasedLevel;
+
+        impl LogLevel for AliasedLevel {
+            #[cfg(feature = "log")]
+            fn default_log() -> Option<log::Level> {
+                Some(log::Level::Error)
+            }
+
+            #[cfg(feature = "tracing")]
+            fn default_tracing() -> Option<LevelFilter> {
+                Some(LevelFilter::ERROR)
+            }
+
+            fn verbose_aliases() -> &'static [&'static str] {
+                &["verbosity", "debug"]
+            }
+
+            fn quiet_aliases() -> &'static [&'static str] {
+                &["silent"]
+            }
+        }
+
+        #[derive(Debug, clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            verbose: Verbosity<AliasedLevel>,
+        }
+
+        use clap::CommandFactory;
+        use clap::Parser;
+        Cli::command().debug_assert();
+
+        let cli = Cli::parse_from(["cli", "--verbosity", "--debug", "-v"]);
+        assert_eq!(cli.verbose.verbose_count(), 3);
+        assert_eq!(cli.verbose.level_name(), "debug");
+
+        let cli = Cli::parse_from(["cli", "--silent"]);
+        assert!(cli.verbose.is_silent());
+
+        #[derive(Debug, clap::Parser)]
+        struct CountCli {
+            #[command(flatten)]
+            verbose: crate::VerbosityCount<AliasedLevel>,
+        }
+
+        CountCli::command().debug_assert();
+        let cli = CountCli::parse_from(["cli", "--verbosity=2", "-v"]);
+        assert_eq!(cli.verbose.verbose_count(), 3);
+    }
+
+    #[test]
+    fn label() {
+        assert_eq!(NoneLevel::label(), "off");
+        assert_eq!(ErrorLevel::label(), "error");
+        assert_eq!(WarnLevel::label(), "warn");
+        assert_eq!(InfoLevel::label(), "info");
+        assert_eq!(DebugLevel::label(), "debug");
+        assert_eq!(TraceLevel::label(), "trace");
+    }
+
+    #[test]
+    fn difference() {
+        let host = Verbosity::<InfoLevel>::new(1, 0);
+        let lib = Verbosity::<InfoLevel>::new(0, 1);
+        assert_eq!(host.difference(&lib), 2);
+        assert_eq!(lib.difference(&host), -2);
+        assert_eq!(host.difference(&host), 0);
+
+        assert_eq!(host.diff_from_default(), 1);
+        assert_eq!(lib.diff_from_default(), -1);
+        assert_eq!(Verbosity::<InfoLevel>::new(2, 2).diff_from_default(), 0);
+
+        // Saturated levels are at most 5 apart
+        let loud = Verbosity::<InfoLevel>::new(9, 0);
+        let silent = Verbosity::<InfoLevel>::new(0, 9);
+        assert_eq!(loud.difference(&silent), 5);
+        assert_eq!(loud.diff_from_default(), 2);
+    }
+
+    #[test]
+    fn hash() {
+        let set = [
+            Verbosity::<InfoLevel>::new(0, 0),
+            Verbosity::<InfoLevel>::new(1, 1),
+            Verbosity::<InfoLevel>::new(3, 3),
+            Verbosity::<InfoLevel>::new(2, 0),
+            Verbosity::<InfoLevel>::new(3, 1),
+        ]
+        .into_iter()
+        .collect::<std::collections::HashSet<_>>();
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&Verbosity::new(0, 0)));
+        assert!(set.contains(&Verbosity::new(2, 0)));
+    }
+
+    #[test]
+    fn merge() {
+        let levels = (-1..=4)
+            .map(Verbosity::<InfoLevel>::from_verbosity)
+            .collect::<Vec<_>>();
+        for a in &levels {
+            assert_eq!(a.merge(a), *a);
+            assert_eq!(a.merge_min(a), *a);
+            for b in &levels {
+                assert_eq!(a.merge(b), b.merge(a));
+                assert_eq!(a.merge_min(b), b.merge_min(a));
+                assert_eq!(a.merge(b).level_name(), a.max(b).level_name());
+                assert_eq!(a.merge_min(b).level_name(), a.min(b).level_name());
+            }
+        }
+
+        let host = Verbosity::<InfoLevel>::new(0, 1);
+        let plugin = Verbosity::<InfoLevel>::new(2, 0);
+        let merged = host.merge(&plugin);
+        assert_eq!((merged.verbose_count(), merged.quiet_count()), (2, 0));
+        let merged = host.merge_min(&plugin);
+        assert_eq!((merged.verbose_count(), merged.quiet_count()), (0, 1));
+    }
+
+    #[test]
+    fn as_i8_saturates() {
+        let loud = Verbosity::<ErrorLevel>::new(200, 0);
+        assert_eq!(loud.as_i8(), 4);
+        assert_eq!(loud.level_name(), "trace");
+
+        let quiet = Verbosity::<ErrorLevel>::new(0, 200);
+        assert_eq!(quiet.as_i8(), -1);
+        assert_eq!(quiet.level_name(), "off");
+
+        let both = Verbosity::<InfoLevel>::new(u8::MAX, u8::MAX);
+        assert_eq!(both.as_i8(), 2);
+        assert_eq!(Verbosity::<TraceLevel>::new(u8::MAX, 0).as_i8(), 4);
+        assert_eq!(Verbosity::<NoneLevel>::new(0, u8::MAX).as_i8(), -1);
+        #[cfg(feature = "log")]
+        {
+            assert_eq!(loud.log_level(), Some(log::Level::Trace));
+            assert_eq!(quiet.log_level(), None);
+            assert_eq!(both.log_level(), Some(log::Level::Info));
+        }
+
+        #[allow(deprecated)]
+        let verbosity = loud.verbosity();
+        assert_eq!(verbosity, loud.as_i8());
+    }
+
+    #[test]
+    #[cfg(feature = "log")]
+    fn try_into_log_level() {
+        let level = log::Level::try_from(Verbosity::<InfoLevel>::new(1, 0));
+        assert_eq!(level, Ok(log::Level::Debug));
+        let level: Option<log::Level> = Verbosity::<InfoLevel>::new(1, 0).into();
+        assert_eq!(level, Some(log::Level::Debug));
+
+        let err = log::Level::try_from(Verbosity::<InfoLevel>::silent()).unwrap_err();
+        assert_eq!(err.to_string(), "all output is disabled");
+        let level: Option<log::Level> = Verbosity::<InfoLevel>::silent().into();
+        assert_eq!(level, None);
+    }
+
+    #[test]
+    #[cfg(feature = "log")]
+    fn into_log_level_filter() {
+        for (verbosity, filter) in Verbosity::levels()
+            .map(Verbosity::<WarnLevel>::from_log_level)
+            .zip([
+                log::LevelFilter::Off,
+                log::LevelFilter::Error,
+                log::LevelFilter::Warn,
+                log::LevelFilter::Info,
+                log::LevelFilter::Debug,
+                log::LevelFilter::Trace,
+            ])
+        {
+            assert_eq!(log::LevelFilter::from(verbosity), filter);
+        }
+
+        let mut builder = env_logger::Builder::new();
+        builder.filter_level(Verbosity::<InfoLevel>::new(0, 0).into());
+    }
+
+    #[test]
+    #[cfg(feature = "tracing")]
+    fn into_tracing_level_filter() {
+        for filter in Verbosity::tracing_levels() {
+            let verbosity = Verbosity::<WarnLevel>::from(filter);
+            assert_eq!(LevelFilter::from(verbosity), filter);
+        }
+
+        let verbosity = Verbosity::<InfoLevel>::new(2, 1);
+        let round_trip = Verbosity::<InfoLevel>::from(LevelFilter::from(verbosity));
+        assert_eq!(round_trip.tracing_level_filter(), LevelFilter::DEBUG);
+        assert_eq!(
+            (round_trip.verbose_count(), round_trip.quiet_count()),
+            (1, 0)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "tracing")]
+    fn tracing_level_directive() {
+        for filter in Verbosity::tracing_levels() {
+            let verbosity = Verbosity::<InfoLevel>::from(filter);
+            let directive = verbosity.tracing_level_directive();
+            assert_eq!(directive.parse::<LevelFilter>().unwrap(), filter);
+        }
+        assert_eq!(
+            Verbosity::<InfoLevel>::new(0, 0).tracing_level_directive(),
+            "info"
+        );
+        assert_eq!(
+            Verbosity::<InfoLevel>::silent().tracing_level_directive(),
+            "off"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "tracing")]
+    fn try_into_tracing_level() {
+        let level = tracing_core::Level::try_from(Verbosity::<InfoLevel>::new(1, 0));
+        assert_eq!(level, Ok(tracing_core::Level::DEBUG));
+        let level: Option<tracing_core::Level> = Verbosity::<InfoLevel>::new(1, 0).into();
+        assert_eq!(level, Some(tracing_core::Level::DEBUG));
+
+        assert!(tracing_core::Level::try_from(Verbosity::<NoneLevel>::new(0, 0)).is_err());
+        let level: Option<tracing_core::Level> = Verbosity::<NoneLevel>::new(0, 0).into();
+        assert_eq!(level, None);
+    }
+
+    #[test]
+    fn from_count() {
+        let counts =
+            |verbosity: Verbosity<ErrorLevel>| (verbosity.verbose_count(), verbosity.quiet_count());
+        assert_eq!(counts(Verbosity::from_count(0)), (0, 0));
+        assert_eq!(counts(Verbosity::from_count(2)), (2, 0));
+        assert_eq!(counts(Verbosity::from_count(-1)), (0, 1));
+
+        // Saturates at off and trace
+        assert_eq!(counts(Verbosity::from_count(-5)), (0, 1));
+        assert_eq!(counts(Verbosity::from_count(i8::MAX)), (4, 0));
+        assert_eq!(counts(Verbosity::from_count(i8::MIN)), (0, 1));
+
+        let trace = Verbosity::<TraceLevel>::from_count(3);
+        assert_eq!((trace.verbose_count(), trace.quiet_count()), (0, 0));
+        let trace = Verbosity::<TraceLevel>::from_count(-3);
+        assert_eq!((trace.verbose_count(), trace.quiet_count()), (0, 3));
+    }
+
+    #[test]
+    fn net_count() {
+        assert_eq!(Verbosity::<InfoLevel>::new(0, 0).net_count(), 0);
+        assert_eq!(Verbosity::<InfoLevel>::new(3, 0).net_count(), 3);
+        assert_eq!(Verbosity::<InfoLevel>::new(1, 2).net_count(), -1);
+        assert_eq!(Verbosity::<InfoLevel>::new(u8::MAX, 0).net_count(), 255);
+        assert_eq!(Verbosity::<InfoLevel>::new(0, u8::MAX).net_count(), -255);
+        assert_eq!(Verbosity::<InfoLevel>::new(u8::MAX, u8::MAX).net_count(), 0);
+
+        for net in -3..=2 {
+            let verbosity = Verbosity::<InfoLevel>::from_count(net);
+            assert_eq!(verbosity.net_count(), net as i16);
+            assert_eq!(verbosity.as_i8() as i16, verbosity.net_count() + 2);
+        }
+    }
+
+    #[test]
+    fn increment() {
+        let mut verbosity = Verbosity::<InfoLevel>::new(0, 0);
+        verbosity.increment();
+        assert_eq!(verbosity.level_name(), "debug");
+        assert!(!verbosity.is_at_max());
+        verbosity.increment_n(10);
+        assert!(verbosity.is_at_max());
+        assert_eq!(verbosity.level_name(), "trace");
+        assert_eq!(verbosity.verbose_count(), 2);
+
+        // Cancels out `--quiet` before adding `--verbose`
+        let mut verbosity = Verbosity::<InfoLevel>::new(0, 2);
+        verbosity.increment();
+        assert_eq!((verbosity.verbose_count(), verbosity.quiet_count()), (0, 1));
+        verbosity.increment_n(2);
+        assert_eq!((verbosity.verbose_count(), verbosity.quiet_count()), (1, 0));
+        assert_eq!(verbosity.level_name(), "debug");
+    }
+
+    #[test]
+    fn decrement() {
+        let mut verbosity = Verbosity::<InfoLevel>::new(0, 0);
+        verbosity.decrement();
+        assert_eq!(verbosity.level_name(), "warn");
+        assert!(!verbosity.is_at_min());
+        verbosity.decrement_n(10);
+        assert!(verbosity.is_at_min());
+        assert!(verbosity.is_silent());
+        assert_eq!(verbosity.quiet_count(), 3);
+
+        // Cancels out `--verbose` before adding `--quiet`
+        let mut verbosity = Verbosity::<InfoLevel>::new(1, 0);
+        verbosity.decrement_n(2);
+        assert_eq!((verbosity.verbose_count(), verbosity.quiet_count()), (0, 1));
+    }
+
+    #[test]
+    fn reset() {
+        let mut verbosity = Verbosity::<InfoLevel>::new(3, 0);
+        assert!(!verbosity.is_default());
+        verbosity.reset();
+        assert!(verbosity.is_default());
+        #[cfg(feature = "log")]
+        assert_eq!(verbosity.log_level(), InfoLevel::default_log());
+        #[cfg(feature = "tracing")]
+        assert_eq!(
+            Some(verbosity.tracing_level_filter()),
+            InfoLevel::default_tracing()
+        );
+
+        // Only raw counts matter, not the resulting level
+        assert!(!Verbosity::<InfoLevel>::new(1, 1).is_default());
+    }
+
+    #[test]
+    fn to_roundtrip_args() {
+        assert_eq!(
+            Verbosity::<ErrorLevel>::new(3, 0).to_roundtrip_args(),
+            ["-vvv"]
+        );
+        assert_eq!(
+            Verbosity::<ErrorLevel>::new(0, 1).to_roundtrip_args(),
+            ["-q"]
+        );
+        assert!(Verbosity::<ErrorLevel>::new(0, 0)
+            .to_roundtrip_args()
+            .is_empty());
+        assert_eq!(
+            Verbosity::<ErrorLevel>::new(2, 0).to_roundtrip_long_args(),
+            ["--verbose", "--verbose"]
+        );
+        // Only what is needed to reach the level
+        assert_eq!(
+            Verbosity::<InfoLevel>::new(0, 9).to_roundtrip_args(),
+            ["-qqq"]
+        );
+
+        #[derive(Debug, clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            verbose: Verbosity<InfoLevel>,
+        }
+
+        use clap::Parser;
+        for verbose in 0..6 {
+            for quiet in 0..6 {
+                let verbosity = Verbosity::<InfoLevel>::new(verbose, quiet);
+                for args in [
+                    verbosity.to_roundtrip_args(),
+                    verbosity.to_roundtrip_long_args(),
+                ] {
+                    let cli = Cli::parse_from(std::iter::once("cli".to_owned()).chain(args));
+                    assert_eq!(cli.verbose, verbosity);
+                    #[cfg(feature = "log")]
+                    assert_eq!(cli.verbose.log_level(), verbosity.log_level());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn const_new() {
+        const QUIET_MODE: Verbosity<InfoLevel> = Verbosity::new(0, 2);
+        static LOUD_MODE: Verbosity<InfoLevel> = Verbosity::new(2, 0);
+
+        assert_eq!(QUIET_MODE.quiet_count(), 2);
+        assert_eq!(QUIET_MODE.as_i8(), 0);
+        assert_eq!(LOUD_MODE.as_i8(), 4);
+    }
+
+    #[test]
+    fn raw_counts() {
+        let verbosity = Verbosity::<ErrorLevel>::new(3, 1);
+        assert_eq!(verbosity.verbose_count(), 3);
+        assert_eq!(verbosity.quiet_count(), 1);
+
+        let round_trip =
+            Verbosity::<ErrorLevel>::new(verbosity.verbose_count(), verbosity.quiet_count());
+        assert_eq!(round_trip.verbose_count(), 3);
+        assert_eq!(round_trip.quiet_count(), 1);
+        assert_eq!(round_trip.as_i8(), verbosity.as_i8());
+    }
+
+    #[test]
+    fn verbose_quiet_predicates() {
+        for (verbose, quiet) in [(0, 0), (2, 0), (0, 1), (1, 1)] {
+            let verbosity = Verbosity::<ErrorLevel>::new(verbose, quiet);
+            assert_eq!(verbosity.above_default(), verbose > 0);
+            assert_eq!(verbosity.below_default(), quiet > 0);
+            assert_eq!(verbosity.at_default(), verbose == 0 && quiet == 0);
+            #[allow(deprecated)]
+            {
+                assert_eq!(verbosity.is_verbose(), verbosity.above_default());
+                assert_eq!(verbosity.is_quiet(), verbosity.below_default());
+            }
+        }
+    }
+
+    #[test]
+    fn ordering() {
+        let mut verbosities = Vec::from([
+            Verbosity::<ErrorLevel>::new(3, 0),
+            Verbosity::<ErrorLevel>::new(0, 1),
+            Verbosity::<ErrorLevel>::new(1, 0),
+            Verbosity::<ErrorLevel>::new(0, 0),
+            Verbosity::<ErrorLevel>::new(2, 0),
+        ]);
+        verbosities.sort();
+        let sorted: Vec<_> = verbosities.iter().map(|v| v.as_i8()).collect();
+        assert_eq!(sorted, [-1, 0, 1, 2, 3]);
+
+        assert!(Verbosity::<ErrorLevel>::new(1, 0) > Verbosity::<ErrorLevel>::new(0, 0));
+        assert_eq!(
+            std::cmp::min(
+                Verbosity::<ErrorLevel>::new(2, 0),
+                Verbosity::<ErrorLevel>::new(0, 1)
+            ),
+            Verbosity::<ErrorLevel>::new(0, 1)
+        );
+    }
+
+    #[test]
+    fn add() {
+        let verbosity = Verbosity::<ErrorLevel>::new(2, 1);
+        let sum = verbosity.clone() + Verbosity::new(0, 0);
+        assert_eq!(sum.verbose_count(), 2);
+        assert_eq!(sum.quiet_count(), 1);
+
+        let sum = Verbosity::<ErrorLevel>::new(1, 2) + Verbosity::new(3, 4);
+        assert_eq!(sum.verbose_count(), 4);
+        assert_eq!(sum.quiet_count(), 6);
+
+        let mut sum = Verbosity::<ErrorLevel>::new(u8::MAX, 1);
+        sum += Verbosity::new(1, u8::MAX);
+        assert_eq!(sum.verbose_count(), u8::MAX);
+        assert_eq!(sum.quiet_count(), u8::MAX);
+    }
+
+    #[test]
+    fn sub() {
+        for (verbose, quiet) in [(0, 0), (1, 0), (0, 3), (2, 5), (u8::MAX, u8::MAX)] {
+            let verbosity = Verbosity::<ErrorLevel>::new(verbose, quiet);
+
+            let diff = verbosity.clone() - verbosity.clone();
+            assert_eq!((diff.verbose_count(), diff.quiet_count()), (0, 0));
+
+            let diff = Verbosity::new(0, 0) - verbosity.clone();
+            assert_eq!((diff.verbose_count(), diff.quiet_count()), (0, 0));
+
+            let mut diff = verbosity.clone();
+            diff -= verbosity;
+            assert_eq!((diff.verbose_count(), diff.quiet_count()), (0, 0));
+        }
+
+        let a = Verbosity::<ErrorLevel>::new(2, 1);
+        let b = Verbosity::<ErrorLevel>::new(3, 4);
+        let round_trip = (a + b.clone()) - b;
+        assert_eq!(
+            (round_trip.verbose_count(), round_trip.quiet_count()),
+            (2, 1)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "log")]
+    fn from_log_level() {
+        for level in [
+            log::Level::Error,
+            log::Level::Warn,
+            log::Level::Info,
+            log::Level::Debug,
+            log::Level::Trace,
+        ] {
+            assert_eq!(
+                Verbosity::<ErrorLevel>::from(level).log_level(),
+                Some(level)
+            );
+            assert_eq!(Verbosity::<NoneLevel>::from(level).log_level(), Some(level));
+            assert_eq!(
+                Verbosity::<TraceLevel>::from(level).log_level(),
+                Some(level)
+            );
+        }
+
+        for filter in [
+            log::LevelFilter::Off,
+            log::LevelFilter::Error,
+            log::LevelFilter::Warn,
+            log::LevelFilter::Info,
+            log::LevelFilter::Debug,
+            log::LevelFilter::Trace,
+        ] {
+            assert_eq!(
+                Verbosity::<ErrorLevel>::from(filter).log_level_filter(),
+                filter
+            );
+            assert_eq!(
+                Verbosity::<InfoLevel>::from(filter).log_level_filter(),
+                filter
+            );
+        }
+
+        assert!(Verbosity::<TraceLevel>::from(log::LevelFilter::Off).is_silent());
+        assert!(Verbosity::<NoneLevel>::from(log::LevelFilter::Off).is_silent());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_round_trip() {
+        let verbosity = Verbosity::<ErrorLevel>::new(2, 0);
+        let json = serde_json::to_string(&verbosity).unwrap();
+        assert_eq!(json, r#"{"verbose":2,"quiet":0}"#);
+
+        let parsed: Verbosity<ErrorLevel> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.verbose_count(), 2);
+        assert_eq!(parsed.quiet_count(), 0);
+
+        let parsed: Verbosity<ErrorLevel> = serde_json::from_str(r#"{"quiet":1}"#).unwrap();
+        assert_eq!(parsed.quiet_count(), 1);
+
+        let err = serde_json::from_str::<Verbosity<ErrorLevel>>(r#"{"verbose":1,"quiet":1}"#)
+            .unwrap_err();
+        assert!(err.to_string().contains("cannot both be set"), "{err}");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_round_trip_without_conflict() {
+        crate::impl_log_level!(OffsetLevel, default: info, conflicts: false);
+
+        let verbosity = Verbosity::<OffsetLevel>::new(1, 1);
+        let json = serde_json::to_string(&verbosity).unwrap();
+        assert_eq!(json, r#"{"verbose":1,"quiet":1}"#);
+
+        let parsed: Verbosity<OffsetLevel> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.verbose_count(), 1);
+        assert_eq!(parsed.quiet_count(), 1);
+        assert_eq!(parsed.level_name(), "info");
+    }
+
+    #[test]
+    fn verbose_conflicts_with_quiet() {
+        crate::impl_log_level!(OffsetLevel, default: info, conflicts: false);
+
+        #[derive(Debug, clap::Parser)]
+        struct Cli<L: LogLevel> {
+            #[command(flatten)]
+            verbose: Verbosity<L>,
+        }
+
+        use clap::CommandFactory;
+        use clap::Parser;
+        Cli::<OffsetLevel>::command().debug_assert();
+        let cli = Cli::<OffsetLevel>::try_parse_from(["cli", "-vv", "-q"]).unwrap();
+        assert_eq!(cli.verbose.verbose_count(), 2);
+        assert_eq!(cli.verbose.quiet_count(), 1);
+        assert_eq!(cli.verbose.level_name(), "debug");
+
+        let err = Cli::<ErrorLevel>::try_parse_from(["cli", "-v", "-q"]).unwrap_err();
+        assert_eq!(err.kind(), clap::error::ErrorKind::ArgumentConflict);
+    }
+
+    #[test]
+    fn from_env() {
+        let var = "CLAP_VERBOSITY_FLAG_TEST_FROM_ENV";
+
+        std::env::remove_var(var);
+        assert_eq!(Verbosity::<ErrorLevel>::from_env(var).as_i8(), 0);
+
+        std::env::set_var(var, "2");
+        let verbosity = Verbosity::<ErrorLevel>::from_env(var);
+        assert_eq!((verbosity.verbose_count(), verbosity.quiet_count()), (2, 0));
+        // The same level, not an offset from the default
+        let verbosity = Verbosity::<InfoLevel>::from_env(var);
+        assert_eq!((verbosity.verbose_count(), verbosity.quiet_count()), (0, 0));
+
+        std::env::set_var(var, "-1");
+        let verbosity = Verbosity::<ErrorLevel>::from_env(var);
+        assert_eq!((verbosity.verbose_count(), verbosity.quiet_count()), (0, 1));
+        assert!(Verbosity::<InfoLevel>::from_env(var).is_silent());
+
+        std::env::set_var(var, "5");
+        assert_eq!(Verbosity::<InfoLevel>::from_env(var).as_i8(), 2);
+
+        std::env::set_var(var, "DEBUG");
+        assert_eq!(Verbosity::<ErrorLevel>::from_env(var).as_i8(), 3);
+        assert_eq!(Verbosity::<InfoLevel>::from_env(var).as_i8(), 3);
+
+        std::env::set_var(var, "off");
+        assert!(Verbosity::<InfoLevel>::from_env(var).is_silent());
+
+        std::env::set_var(var, "loud");
+        assert_eq!(Verbosity::<InfoLevel>::from_env(var).as_i8(), 2);
+
+        std::env::remove_var(var);
+    }
+
+    #[test]
+    fn from_env_matches_from_str() {
+        let var = "CLAP_VERBOSITY_FLAG_TEST_FROM_ENV_MATCHES_FROM_STR";
+
+        for input in ["-1", "0", "2", "4", "off", "Debug", " warn "] {
+            std::env::set_var(var, input);
+            let parsed = input.parse::<Verbosity<InfoLevel>>().unwrap();
+            let from_env = Verbosity::<InfoLevel>::from_env(var);
+            assert_eq!(from_env.as_i8(), parsed.as_i8(), "{input:?}");
+            assert_eq!(
+                from_env.verbose_count(),
+                parsed.verbose_count(),
+                "{input:?}"
+            );
+            assert_eq!(from_env.quiet_count(), parsed.quiet_count(), "{input:?}");
+        }
+
+        for verbosity in [
+            Verbosity::<InfoLevel>::silent(),
+            Verbosity::new(0, 1),
+            Verbosity::new(0, 0),
+            Verbosity::new(2, 0),
+        ] {
+            let value = verbosity.to_env_logger_filter_string();
+            std::env::set_var(var, &value);
+            assert_eq!(Verbosity::<InfoLevel>::from_env(var), verbosity, "{value}");
+            assert_eq!(
+                value.parse::<Verbosity<InfoLevel>>(),
+                Ok(verbosity),
+                "{value}"
+            );
+        }
+
+        for value in [Verbosity::<InfoLevel>::new(1, 0).as_i8(), -1, 4] {
+            let value = value.to_string();
+            std::env::set_var(var, &value);
+            assert_eq!(
+                Verbosity::<InfoLevel>::from_env(var),
+                value.parse::<Verbosity<InfoLevel>>().unwrap(),
+                "{value}"
+            );
+        }
+
+        std::env::remove_var(var);
+    }
+
+    #[test]
+    fn to_env_logger_filter_string() {
+        assert_eq!(
+            Verbosity::<InfoLevel>::new(0, 0).to_env_logger_filter_string(),
+            "info"
+        );
+        assert_eq!(
+            Verbosity::<InfoLevel>::new(9, 0).to_env_logger_filter_string(),
+            "trace"
+        );
+        assert_eq!(
+            Verbosity::<InfoLevel>::silent().to_env_logger_filter_string(),
+            "off"
+        );
+    }
+
+    #[test]
+    fn with_env_prefix() {
+        let prefix = "CLAP_VERBOSITY_FLAG_TEST_PREFIX";
+        let verbose = format!("{prefix}_VERBOSE");
+        let quiet = format!("{prefix}_QUIET");
+        let counts = |verbosity: &Verbosity<ErrorLevel>| {
+            (verbosity.verbose_count(), verbosity.quiet_count())
+        };
+
+        std::env::remove_var(&verbose);
+        std::env::remove_var(&quiet);
+        let verbosity = Verbosity::<ErrorLevel>::new(1, 0);
+        assert_eq!(counts(&verbosity.with_env_prefix(prefix)), (1, 0));
+
+        std::env::set_var(&verbose, "2");
+        assert_eq!(
+            counts(&Verbosity::new(0, 0).with_env_prefix(prefix)),
+            (2, 0)
+        );
+        assert_eq!(counts(&verbosity.with_env_prefix(prefix)), (3, 0));
+
+        // Flags and variables cancel out
+        std::env::set_var(&quiet, " 1 ");
+        let verbosity = Verbosity::<ErrorLevel>::new(0, 2).with_env_prefix(prefix);
+        assert_eq!(counts(&verbosity), (2, 3));
+        assert_eq!(verbosity.as_i8(), -1);
+
+        std::env::set_var(&verbose, "255");
+        assert_eq!(
+            counts(&Verbosity::new(9, 0).with_verbose_env(&verbose)),
+            (255, 0)
+        );
+
+        std::env::set_var(&verbose, "-1");
+        std::env::set_var(&quiet, "lots");
+        assert_eq!(
+            counts(&Verbosity::new(1, 1).with_env_prefix(prefix)),
+            (1, 1)
+        );
+
+        std::env::remove_var(&verbose);
+        std::env::remove_var(&quiet);
+    }
+
+    #[test]
+    fn silent_and_max_verbose() {
+        fn check<L: LogLevel>() {
+            let silent = Verbosity::<L>::silent();
+            assert!(silent.is_silent());
+            assert_eq!(silent.as_i8(), -1);
+            assert_eq!(silent.verbose_count(), 0);
+
+            let max = Verbosity::<L>::max_verbose();
+            assert_eq!(max.as_i8(), 4);
+            assert_eq!(max.quiet_count(), 0);
+        }
+
+        check::<NoneLevel>();
+        check::<ErrorLevel>();
+        check::<WarnLevel>();
+        check::<InfoLevel>();
+        check::<DebugLevel>();
+        check::<TraceLevel>();
+
+        assert_eq!(Verbosity::<InfoLevel>::silent().quiet_count(), 3);
+        assert_eq!(Verbosity::<NoneLevel>::silent().quiet_count(), 0);
+        assert_eq!(Verbosity::<InfoLevel>::max_verbose().verbose_count(), 2);
+    }
+
+    #[test]
+    #[cfg(feature = "log")]
+    fn from_log_level_round_trip() {
+        for level in [
+            None,
+            Some(log::Level::Error),
+            Some(log::Level::Warn),
+            Some(log::Level::Info),
+            Some(log::Level::Debug),
+            Some(log::Level::Trace),
+        ] {
+            assert_eq!(
+                Verbosity::<WarnLevel>::from_log_level(level).log_level(),
+                level
+            );
+        }
+
+        for verbosity in [
+            Verbosity::<InfoLevel>::new(0, 0),
+            Verbosity::new(2, 0),
+            Verbosity::new(0, 1),
+            Verbosity::new(0, 5),
+        ] {
+            let round_trip = Verbosity::<InfoLevel>::from_log_level(verbosity.log_level());
+            assert_eq!(round_trip.log_level(), verbosity.log_level());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "tracing")]
+    fn from_tracing_level_filter_round_trip() {
+        for filter in [
+            LevelFilter::OFF,
+            LevelFilter::ERROR,
+            LevelFilter::WARN,
+            LevelFilter::INFO,
+            LevelFilter::DEBUG,
+            LevelFilter::TRACE,
+        ] {
+            let verbosity = Verbosity::<WarnLevel>::from_tracing_level_filter(filter);
+            assert_eq!(verbosity.tracing_level_filter(), filter);
+
+            let verbosity = Verbosity::<InfoLevel>::from(filter);
+            assert_eq!(verbosity.tracing_level_filter(), filter);
+        }
+        assert!(Verbosity::<InfoLevel>::from(LevelFilter::OFF).is_silent());
+    }
+
+    #[test]
+    #[cfg(feature = "tracing")]
+    fn from_tracing_level() {
+        for level in [
+            tracing_core::Level::ERROR,
+            tracing_core::Level::WARN,
+            tracing_core::Level::INFO,
+            tracing_core::Level::DEBUG,
+            tracing_core::Level::TRACE,
+        ] {
+            let verbosity = Verbosity::<ErrorLevel>::from(level);
+            assert_eq!(
+                verbosity.tracing_level_filter(),
+                LevelFilter::from_level(level)
+            );
+
+            let verbosity = Verbosity::<TraceLevel>::from(level);
+            assert_eq!(
+                verbosity.tracing_level_filter(),
+                LevelFilter::from_level(level)
+            );
+        }
+        assert_eq!(
+            Verbosity::<ErrorLevel>::from(tracing_core::Level::DEBUG).tracing_level_filter(),
+            LevelFilter::DEBUG
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "log")]
+    fn clamped() {
+        let min = Some(log::Level::Warn);
+        let max = Some(log::Level::Debug);
+
+        let too_quiet = Verbosity::<InfoLevel>::new(0, 3).clamped(min, max);
+        assert_eq!(too_quiet.log_level(), min);
+
+        let too_loud = Verbosity::<InfoLevel>::new(4, 0).clamped(min, max);
+        assert_eq!(too_loud.log_level(), max);
+
+        let in_range = Verbosity::<InfoLevel>::new(1, 0).clamped(min, max);
+        assert_eq!(in_range.log_level(), Some(log::Level::Debug));
+        assert_eq!(in_range.verbose_count(), 1);
+
+        let silent = Verbosity::<InfoLevel>::new(0, 5).clamped(None, max);
+        assert!(silent.is_silent());
+        assert_eq!(silent.quiet_count(), 5);
+    }
+
+    #[test]
+    #[cfg(all(feature = "log", debug_assertions))]
+    #[should_panic = "must not be more verbose"]
+    fn clamped_inverted_range() {
+        Verbosity::<InfoLevel>::new(0, 0).clamped(Some(log::Level::Debug), Some(log::Level::Warn));
+    }
+
+    #[test]
+    #[cfg(feature = "log")]
+    fn clamp_to_range() {
+        let min = Some(log::Level::Warn);
+        let max = Some(log::Level::Debug);
+
+        let in_range = Verbosity::<InfoLevel>::new(0, 0).clamp_to_range(min, max);
+        assert_eq!(in_range.log_level(), Some(log::Level::Info));
+        assert_eq!((in_range.verbose_count(), in_range.quiet_count()), (0, 0));
+
+        let too_loud = Verbosity::<InfoLevel>::new(5, 0).clamp_to_range(min, max);
+        assert_eq!(too_loud.log_level(), max);
+        assert_eq!((too_loud.verbose_count(), too_loud.quiet_count()), (1, 0));
+
+        let too_quiet = Verbosity::<InfoLevel>::new(0, 4).clamp_to_range(min, max);
+        assert_eq!(too_quiet.log_level(), min);
+        assert_eq!((too_quiet.verbose_count(), too_quiet.quiet_count()), (0, 1));
+
+        let off = Verbosity::<InfoLevel>::new(2, 0).clamp_to_range(None, None);
+        assert!(off.is_silent());
+        assert_eq!((off.verbose_count(), off.quiet_count()), (0, 3));
+
+        let silent = Verbosity::<InfoLevel>::new(0, 9).clamp_to_range(None, max);
+        assert!(silent.is_silent());
+        assert_eq!(silent.quiet_count(), 3);
+    }
+
+    #[test]
+    #[cfg(all(feature = "log", debug_assertions))]
+    #[should_panic = "invalid range"]
+    fn clamp_to_range_inverted() {
+        Verbosity::<InfoLevel>::new(0, 0)
+            .clamp_to_range(Some(log::Level::Debug), Some(log::Level::Warn));
+    }
+
+    #[test]
+    #[cfg(all(feature = "log", not(debug_assertions)))]
+    fn clamp_to_range_inverted() {
+        let verbosity = Verbosity::<InfoLevel>::new(0, 0)
+            .clamp_to_range(Some(log::Level::Debug), Some(log::Level::Warn));
+        assert_eq!(verbosity.log_level(), Some(log::Level::Warn));
+    }
+
+    #[test]
+    fn parse() {
+        for (input, expected) in [
+            ("off", -1),
+            ("error", 0),
+            ("warn", 1),
+            ("info", 2),
+            ("debug", 3),
+            ("trace", 4),
+            ("TRACE", 4),
+            ("Info", 2),
+            ("-1", -1),
+            ("0", 0),
+            ("4", 4),
+            (" 2 ", 2),
+        ] {
+            let verbosity: Verbosity<InfoLevel> = input.parse().unwrap();
+            assert_eq!(verbosity.as_i8(), expected, "{input:?}");
+            let verbosity = Verbosity::<NoneLevel>::try_from(input).unwrap();
+            assert_eq!(verbosity.as_i8(), expected, "{input:?}");
+        }
+
+        for input in ["", "loud", "5", "-2", "1.0", "warning"] {
+            let err = input.parse::<Verbosity>().unwrap_err();
+            assert_eq!(err.input(), input);
+            assert!(err.to_string().contains("expected one of"), "{err}");
+        }
+    }
+
+    #[test]
+    fn parse_level_error() {
+        let err = "loud".parse::<Verbosity>().unwrap_err();
+        assert_eq!(err.clone(), err);
+        assert_eq!(
+            err.to_string(),
+            "invalid level `loud`, expected one of `off`, `error`, `warn`, `info`, `debug`, `trace`, `-1`, `0`, `1`, `2`, `3`, `4`"
+        );
+
+        for value in ParseLevelError::valid_values() {
+            assert!(value.parse::<Verbosity>().is_ok(), "{value}");
+        }
+    }
+
+    #[test]
+    fn display() {
+        for (quiet, expected) in [
+            (6, "off"),
+            (5, "off"),
+            (4, "error"),
+            (2, "info"),
+            (1, "debug"),
+        ] {
+            let verbosity = Verbosity::<TraceLevel>::new(0, quiet);
+            assert_eq!(verbosity.to_string(), expected);
+        }
+        assert_eq!(Verbosity::<ErrorLevel>::new(1, 0).to_string(), "warn");
+        assert_eq!(Verbosity::<ErrorLevel>::new(9, 0).to_string(), "trace");
+        assert_eq!(
+            format!("Log level: {}", Verbosity::<InfoLevel>::new(0, 0)),
+            "Log level: info"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "log")]
+    fn levels() {
+        let levels = Verbosity::levels().collect::<Vec<_>>();
+        assert_eq!(levels.len(), 6);
+        assert_eq!(levels.first(), Some(&None));
+        assert_eq!(levels.last(), Some(&Some(log::Level::Trace)));
+        assert!(levels.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    #[cfg(feature = "tracing")]
+    fn tracing_levels() {
+        let levels = Verbosity::tracing_levels().collect::<Vec<_>>();
+        assert_eq!(levels.len(), 6);
+        assert_eq!(levels.first(), Some(&LevelFilter::OFF));
+        assert_eq!(levels.last(), Some(&LevelFilter::TRACE));
+        assert!(levels.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn level_name() {
+        for (verbose, expected) in ["error", "warn", "info", "debug", "trace"]
+            .into_iter()
+            .enumerate()
+        {
+            let verbosity = Verbosity::<ErrorLevel>::new(verbose as u8, 0);
+            assert_eq!(verbosity.level_name(), expected);
+            assert_eq!(verbosity.level_name(), verbosity.to_string());
+        }
+        assert_eq!(Verbosity::<ErrorLevel>::new(0, 1).level_name(), "off");
+
+        // Boundaries
+        assert_eq!(Verbosity::<ErrorLevel>::new(0, 100).level_name(), "off");
+        assert_eq!(Verbosity::<ErrorLevel>::new(100, 0).level_name(), "trace");
+        assert_eq!(Verbosity::<NoneLevel>::new(0, 0).level_name(), "off");
+        assert_eq!(Verbosity::<TraceLevel>::new(1, 0).level_name(), "trace");
+    }
+
+    #[test]
+    #[cfg(feature = "arbitrary")]
+    fn arbitrary_counts_are_bounded() {
+        use arbitrary::Arbitrary;
+
+        let data: Vec<u8> = (0..=u8::MAX).collect();
+        let mut u = arbitrary::Unstructured::new(&data);
+        while !u.is_empty() {
+            let verbosity = Verbosity::<ErrorLevel>::arbitrary(&mut u).unwrap();
+            assert!(verbosity.verbose_count() <= 8);
+            assert!(verbosity.quiet_count() <= 8);
+        }
+    }
+
+    #[cfg(any(feature = "log", feature = "tracing"))]
+    #[derive(Copy, Clone, Debug, Default)]
+    struct CappedLevel;
+
+    #[cfg(any(feature = "log", feature = "tracing"))]
+    impl LogLevel for CappedLevel {
+        #[cfg(feature = "log")]
+        fn default_log() -> Option<log::Level> {
+            Some(log::Level::Warn)
+        }
+
+        #[cfg(feature = "tracing")]
+        fn default_tracing() -> Option<LevelFilter> {
+            Some(LevelFilter::WARN)
+        }
+
+        fn verbose_help() -> Option<&'static str> {
+            Some("More output per occurrence, up to info")
+        }
+
+        #[cfg(feature = "log")]
+        fn max_level() -> Option<log::Level> {
+            Some(log::Level::Info)
+        }
+
+        #[cfg(feature = "tracing")]
+        fn max_tracing_level() -> LevelFilter {
+            LevelFilter::INFO
+        }
+    }
+
+    #[test]
+    #[cfg(any(feature = "log", feature = "tracing"))]
+    fn max_level() {
+        #[derive(Debug, clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            verbose: Verbosity<CappedLevel>,
+        }
+
+        use clap::CommandFactory;
+        let cmd = Cli::command();
+        cmd.clone().debug_assert();
+        let help = cmd
+            .get_arguments()
+            .find(|arg| arg.get_id() == "verbose")
+            .and_then(|arg| arg.get_help())
+            .unwrap()
+            .to_string();
+        assert!(help.contains("up to info"), "{help}");
+
+        for verbose in [1, 2, 4, 10] {
+            let verbosity = Verbosity::<CappedLevel>::new(verbose, 0);
+            #[cfg(feature = "log")]
+            assert_eq!(verbosity.log_level(), Some(log::Level::Info));
+            #[cfg(feature = "tracing")]
+            assert_eq!(verbosity.tracing_level_filter(), LevelFilter::INFO);
+            assert_eq!(verbosity.level_name(), "info");
+            assert_eq!(verbosity.to_string(), "info");
+            assert_eq!(verbosity.level_index(), 3);
+            assert!(verbosity.is_at_max());
+            assert_eq!(verbosity.levels_above_current(), 0);
+        }
+
+        let mut verbosity = Verbosity::<CappedLevel>::new(0, 0);
+        verbosity.increment_n(5);
+        assert_eq!(verbosity.verbose_count(), 1);
+        assert!(Verbosity::<CappedLevel>::new(1, 0)
+            .checked_increment()
+            .is_none());
+
+        let verbosity = Verbosity::<CappedLevel>::new(0, 1);
+        #[cfg(feature = "log")]
+        assert_eq!(verbosity.log_level(), Some(log::Level::Error));
+        #[cfg(feature = "tracing")]
+        assert_eq!(verbosity.tracing_level_filter(), LevelFilter::ERROR);
+    }
+
+    #[cfg(any(feature = "log", feature = "tracing"))]
+    #[derive(Copy, Clone, Debug, Default)]
+    struct SteppedLevel;
+
+    #[cfg(any(feature = "log", feature = "tracing"))]
+    impl LogLevel for SteppedLevel {
+        #[cfg(feature = "log")]
+        fn default_log() -> Option<log::Level> {
+            Some(log::Level::Warn)
+        }
+
+        #[cfg(feature = "tracing")]
+        fn default_tracing() -> Option<LevelFilter> {
+            Some(LevelFilter::WARN)
+        }
+
+        fn max_verbose_steps() -> u8 {
+            1
+        }
+
+        fn max_quiet_steps() -> u8 {
+            0
+        }
+    }
+
+    #[test]
+    #[cfg(any(feature = "log", feature = "tracing"))]
+    fn max_steps() {
+        #[derive(Debug, clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            verbose: Verbosity<SteppedLevel>,
+        }
+
+        use clap::CommandFactory;
+        use clap::Parser;
+        let cmd = Cli::command();
+        cmd.clone().debug_assert();
+        let help = |id: &str| {
+            cmd.get_arguments()
+                .find(|arg| arg.get_id() == id)
+                .and_then(|arg| arg.get_help())
+                .unwrap()
+                .to_string()
+        };
+        assert_eq!(help(VERBOSE), "More output per occurrence");
+        assert_eq!(help(QUIET), "Less output per occurrence");
+
+        let cli = Cli::parse_from(["cli", "-vvvv"]);
+        assert_eq!(cli.verbose.verbose_count(), 4);
+        assert_eq!(cli.verbose.level_name(), "info");
+
+        let cli = Cli::parse_from(["cli", "-qq"]);
+        assert_eq!(cli.verbose.level_name(), "warn");
+
+        assert_eq!(Verbosity::<SteppedLevel>::new(1, 0).level_name(), "info");
+    }
+
+    #[derive(Copy, Clone, Debug, Default)]
+    struct DoubleStepLevel;
+
+    impl LogLevel for DoubleStepLevel {
+        #[cfg(feature = "log")]
+        fn default_log() -> Option<log::Level> {
+            Some(log::Level::Error)
+        }
+
+        #[cfg(feature = "tracing")]
+        fn default_tracing() -> Option<LevelFilter> {
+            Some(LevelFilter::ERROR)
+        }
+
+        fn step_size() -> u8 {
+            2
+        }
+    }
+
+    #[test]
+    fn step_size() {
+        assert_eq!(Verbosity::<DoubleStepLevel>::new(1, 0).level_name(), "info");
+        assert_eq!(
+            Verbosity::<DoubleStepLevel>::new(2, 0).level_name(),
+            "trace"
+        );
+        assert_eq!(Verbosity::<DoubleStepLevel>::new(0, 1).level_name(), "off");
+
+        // Saturates
+        assert_eq!(Verbosity::<DoubleStepLevel>::new(3, 0).as_i8(), 4);
+        assert_eq!(Verbosity::<DoubleStepLevel>::new(u8::MAX, 0).as_i8(), 4);
+        assert_eq!(Verbosity::<DoubleStepLevel>::new(0, u8::MAX).as_i8(), -1);
+        assert_eq!(
+            Verbosity::<DoubleStepLevel>::new(u8::MAX, u8::MAX).as_i8(),
+            0
+        );
+
+        // Reaching a level in between goes past it
+        let warn = Verbosity::<DoubleStepLevel>::from_count(1);
+        assert_eq!(warn.verbose_count(), 1);
+        assert_eq!(warn.level_name(), "info");
+
+        let mut verbosity = Verbosity::<DoubleStepLevel>::new(0, 0);
+        verbosity.increment_n(5);
+        assert_eq!(verbosity.verbose_count(), 2);
+    }
+
+    #[derive(Copy, Clone, Debug, Default)]
+    struct ZeroStepLevel;
+
+    impl LogLevel for ZeroStepLevel {
+        #[cfg(feature = "log")]
+        fn default_log() -> Option<log::Level> {
+            Some(log::Level::Error)
+        }
+
+        #[cfg(feature = "tracing")]
+        fn default_tracing() -> Option<LevelFilter> {
+            Some(LevelFilter::ERROR)
+        }
+
+        fn step_size() -> u8 {
+            0
+        }
+    }
+
+    #[test]
+    fn zero_step_size() {
+        assert_eq!(Verbosity::<ZeroStepLevel>::new(1, 0).level_name(), "warn");
+        assert_eq!(Verbosity::<ZeroStepLevel>::new(0, 1).level_name(), "off");
+        assert_eq!(Verbosity::<ZeroStepLevel>::from_count(3).verbose_count(), 3);
+    }
+
+    #[cfg(any(feature = "log", feature = "tracing"))]
+    #[derive(Copy, Clone, Debug, Default)]
+    struct FlooredLevel;
+
+    #[cfg(any(feature = "log", feature = "tracing"))]
+    impl LogLevel for FlooredLevel {
+        #[cfg(feature = "log")]
+        fn default_log() -> Option<log::Level> {
+            Some(log::Level::Info)
+        }
+
+        #[cfg(feature = "tracing")]
+        fn default_tracing() -> Option<LevelFilter> {
+            Some(LevelFilter::INFO)
+        }
+
+        fn quiet_help() -> Option<&'static str> {
+            Some("Less output per occurrence, errors are always shown")
+        }
+
+        #[cfg(feature = "log")]
+        fn min_level() -> Option<log::Level> {
+            Some(log::Level::Error)
+        }
+
+        #[cfg(feature = "tracing")]
+        fn min_tracing_level() -> LevelFilter {
+            LevelFilter::ERROR
+        }
+    }
+
+    #[test]
+    #[cfg(any(feature = "log", feature = "tracing"))]
+    fn min_level() {
+        #[derive(Debug, clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            verbose: Verbosity<FlooredLevel>,
+        }
+
+        use clap::CommandFactory;
+        let cmd = Cli::command();
+        cmd.clone().debug_assert();
+        let help = cmd
+            .get_arguments()
+            .find(|arg| arg.get_id() == "quiet")
+            .and_then(|arg| arg.get_help())
+            .unwrap()
+            .to_string();
+        assert_eq!(help, "Less output per occurrence, errors are always shown");
+
+        for quiet in [2, 3, 10] {
+            let verbosity = Verbosity::<FlooredLevel>::new(0, quiet);
+            assert!(!verbosity.is_silent());
+            assert_eq!(verbosity.level_name(), "error");
+            assert!(verbosity.is_at_min());
+            assert_eq!(verbosity.levels_below_current(), 0);
+            #[cfg(feature = "log")]
+            assert_eq!(verbosity.log_level(), Some(log::Level::Error));
+            #[cfg(feature = "tracing")]
+            assert_eq!(verbosity.tracing_level_filter(), LevelFilter::ERROR);
+        }
+
+        let verbosity = Verbosity::<FlooredLevel>::new(0, 1);
+        #[cfg(feature = "log")]
+        assert_eq!(verbosity.log_level(), Some(log::Level::Warn));
+        #[cfg(feature = "tracing")]
+        assert_eq!(verbosity.tracing_level_filter(), LevelFilter::WARN);
+    }
+
+    #[test]
+    #[cfg(not(any(feature = "log", feature = "tracing")))]
+    fn known_level_bounds() {
+        #[derive(Copy, Clone, Debug, Default)]
+        struct BoundedLevel;
+
+        impl LogLevel for BoundedLevel {
+            fn default_filter_string() -> &'static str {
+                "info"
+            }
+
+            fn max_known_level() -> KnownLevel {
+                KnownLevel::Debug
+            }
+
+            fn min_known_level() -> KnownLevel {
+                KnownLevel::Warn
+            }
+        }
+
+        let verbosity = Verbosity::<BoundedLevel>::new(3, 0);
+        assert_eq!(verbosity.level_name(), "debug");
+        assert!(verbosity.is_at_max());
+
+        let verbosity = Verbosity::<BoundedLevel>::new(0, 3);
+        assert_eq!(verbosity.level_name(), "warn");
+        assert!(verbosity.is_at_min());
+        assert!(!verbosity.is_silent());
+    }
+
+    #[test]
+    fn long_names() {
+        #[derive(Copy, Clone, Debug, Default)]
+        struct RenamedLevel;
+
+        impl LogLevel for RenamedLevel {
+            #[cfg(feature = "log")]
+            fn default_log() -> Option<log::Level> {
+                Some(log::Level::Error)
+            }
+
+            #[cfg(feature = "tracing")]
+            fn default_tracing() -> Option<LevelFilter> {
+                Some(LevelFilter::ERROR)
+            }
+
+            fn verbose_long_name() -> &'static str {
+                "debug"
+            }
+
+            fn quiet_long_name() -> &'static str {
+                "suppress"
+            }
+        }
+
+        #[derive(Debug, clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            verbose: Verbosity<RenamedLevel>,
+        }
+
+        use clap::CommandFactory;
+        use clap::Parser;
+        Cli::command().debug_assert();
+
+        let cli = Cli::parse_from(["cli", "--debug", "--debug", "-v"]);
+        assert_eq!(cli.verbose.verbose_count(), 3);
+        let cli = Cli::parse_from(["cli", "--suppress"]);
+        assert_eq!(cli.verbose.quiet_count(), 1);
+        assert!(Cli::try_parse_from(["cli", "--verbose"]).is_err());
+        assert!(Cli::try_parse_from(["cli", "--debug", "--suppress"]).is_err());
+    }
+
+    #[test]
+    fn short_chars() {
+        #[derive(Copy, Clone, Debug, Default)]
+        struct ShortLevel;
+
+        impl LogLevel for ShortLevel {
+            #[cfg(feature = "log")]
+            fn default_log() -> Option<log::Level> {
+                Some(log::Level::Error)
+            }
+
+            #[cfg(feature = "tracing")]
+            fn default_tracing() -> Option<LevelFilter> {
+                Some(LevelFilter::ERROR)
+            }
+
+            fn verbose_short_char() -> Option<char> {
+                None
+            }
+
+            fn quiet_short_char() -> Option<char> {
+                Some('s')
+            }
+        }
+
+        #[derive(Debug, clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            verbose: Verbosity<ShortLevel>,
+
+            #[arg(short = 'v')]
+            version: bool,
+        }
+
+        use clap::CommandFactory;
+        use clap::Parser;
+        Cli::command().debug_assert();
+
+        let cli = Cli::parse_from(["cli", "-v", "--verbose"]);
+        assert!(cli.version);
+        assert_eq!(cli.verbose.verbose_count(), 1);
+        let cli = Cli::parse_from(["cli", "-ss"]);
+        assert_eq!(cli.verbose.quiet_count(), 2);
+        assert!(Cli::try_parse_from(["cli", "-q"]).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "log")]
+    fn is_at_least_most() {
+        let levels = [
+            log::Level::Error,
+            log::Level::Warn,
+            log::Level::Info,
+            log::Level::Debug,
+            log::Level::Trace,
+        ];
+        for verbosity in -1..=4 {
+            let v = Verbosity::<ErrorLevel>::from_verbosity(verbosity);
+            for (level, value) in levels.into_iter().zip(0..) {
+                assert_eq!(v.is_at_least(level), verbosity >= value, "{v} {level}");
+                assert_eq!(v.is_at_most(level), verbosity <= value, "{v} {level}");
+            }
+        }
+
+        let v = Verbosity::<InfoLevel>::new(0, 0);
+        assert!(v.is_at_least(log::Level::Info));
+        assert!(!v.is_at_least(log::Level::Debug));
+        assert!(v.is_at_most(log::Level::Info));
+        assert!(!v.is_at_most(log::Level::Warn));
+    }
+
+    #[test]
+    #[cfg(feature = "tracing")]
+    fn is_at_least_most_tracing() {
+        use tracing_core::Level;
+
+        let levels = [
+            Level::ERROR,
+            Level::WARN,
+            Level::INFO,
+            Level::DEBUG,
+            Level::TRACE,
+        ];
+        for verbosity in -1..=4 {
+            let v = Verbosity::<ErrorLevel>::from_verbosity(verbosity);
+            for (level, value) in levels.into_iter().zip(0..) {
+                assert_eq!(
+                    v.is_at_least_tracing(level),
+                    verbosity >= value,
+                    "{v} {level}"
+                );
+                assert_eq!(
+                    v.is_at_most_tracing(level),
+                    verbosity <= value,
+                    "{v} {level}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn is_at_max_min_levels() {
+        for verbosity in -1..=4 {
+            let v = Verbosity::<ErrorLevel>::from_verbosity(verbosity);
+            assert_eq!(v.is_at_max(), v.level_name() == "trace", "{v}");
+            assert_eq!(v.is_at_min(), v.level_name() == "off", "{v}");
+            #[cfg(feature = "log")]
+            {
+                assert_eq!(v.is_at_max(), v.log_level() == Some(log::Level::Trace));
+                assert_eq!(v.is_at_min(), v.log_level().is_none());
+            }
+            #[cfg(feature = "tracing")]
+            {
+                assert_eq!(
+                    v.is_at_max(),
+                    v.tracing_level_filter() == LevelFilter::TRACE
+                );
+                assert_eq!(v.is_at_min(), v.tracing_level_filter() == LevelFilter::OFF);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "log")]
+    fn level_log_round_trip() {
+        for level in Verbosity::levels() {
+            assert_eq!(level_enum_log(level_value_log(level)), level);
+        }
+        for verbosity in -1..=4 {
+            assert_eq!(level_value_log(level_enum_log(verbosity)), verbosity);
+        }
+        assert_eq!(level_enum_log(i8::MIN), None);
+        assert_eq!(level_enum_log(i8::MAX), Some(log::Level::Trace));
+    }
+
+    #[test]
+    #[cfg(feature = "tracing")]
+    fn level_tracing_round_trip() {
+        for level in Verbosity::tracing_levels() {
+            assert_eq!(level_enum_tracing(level_value_tracing(Some(level))), level);
+        }
+        for verbosity in -1..=4 {
+            assert_eq!(
+                level_value_tracing(Some(level_enum_tracing(verbosity))),
+                verbosity
+            );
+        }
+        assert_eq!(level_value_tracing(None), -1);
+        assert_eq!(level_enum_tracing(i8::MIN), LevelFilter::OFF);
+        assert_eq!(level_enum_tracing(i8::MAX), LevelFilter::TRACE);
+    }
+
+    #[test]
+    fn level_index() {
+        for (index, verbosity) in (-1..=4).enumerate() {
+            let v = Verbosity::<ErrorLevel>::from_verbosity(verbosity);
+            assert_eq!(v.level_index(), index);
+            assert_eq!(LEVEL_NAMES[v.level_index()], v.level_name());
+        }
+        assert_eq!(Verbosity::<ErrorLevel>::new(0, 200).level_index(), 0);
+        assert_eq!(
+            Verbosity::<ErrorLevel>::new(200, 0).level_index(),
+            LEVEL_COUNT - 1
+        );
+    }
+
+    #[test]
+    fn was_modified() {
+        for (verbose, quiet, modified) in [(0, 0, false), (1, 0, true), (0, 1, true), (1, 1, true)]
+        {
+            let verbosity = Verbosity::<InfoLevel>::new(verbose, quiet);
+            assert_eq!(verbosity.is_default(), !modified);
+            assert_eq!(verbosity.was_modified(), modified);
+        }
+    }
+
+    #[test]
+    fn levels_above_below_current() {
+        let verbosity = Verbosity::<WarnLevel>::new(2, 0);
+        assert_eq!(verbosity.level_name(), "debug");
+        assert_eq!(verbosity.levels_above_current(), 1);
+        assert_eq!(verbosity.levels_below_current(), 4);
+
+        let verbosity = Verbosity::<ErrorLevel>::new(4, 0);
+        assert_eq!(verbosity.levels_above_current(), 0);
+        assert_eq!(verbosity.levels_below_current(), 5);
+
+        let verbosity = Verbosity::<ErrorLevel>::new(0, 1);
+        assert_eq!(verbosity.levels_above_current(), 5);
+        assert_eq!(verbosity.levels_below_current(), 0);
+
+        for verbosity in -1..=4 {
+            let v = Verbosity::<ErrorLevel>::from_verbosity(verbosity);
+            assert_eq!(
+                v.levels_above_current() + v.levels_below_current(),
+                LEVEL_COUNT - 1
+            );
+            assert_eq!(v.levels_above_current() == 0, v.is_at_max());
+            assert_eq!(v.levels_below_current() == 0, v.is_at_min());
+        }
+    }
+
+    #[test]
+    fn shell_export_string() {
+        let verbosity = Verbosity::<InfoLevel>::new(1, 0);
+        assert_eq!(verbosity.shell_export_string(None), "export RUST_LOG=debug");
+        assert_eq!(
+            verbosity.shell_export_string(Some("MY_APP")),
+            "export MY_APP_VERBOSE=1 MY_APP_QUIET=0"
+        );
+
+        let silent = Verbosity::<InfoLevel>::new(0, 3);
+        assert_eq!(silent.shell_export_string(None), "export RUST_LOG=off");
+        assert_eq!(
+            silent.shell_export_string(Some("APP")),
+            "export APP_VERBOSE=0 APP_QUIET=3"
+        );
+    }
+
+    #[test]
+    fn powershell_export_string() {
+        let verbosity = Verbosity::<InfoLevel>::new(1, 0);
+        assert_eq!(
+            verbosity.powershell_export_string(None),
+            r#"$env:RUST_LOG = "debug""#
+        );
+        assert_eq!(
+            verbosity.powershell_export_string(Some("MY_APP")),
+            r#"$env:MY_APP_VERBOSE = "1"; $env:MY_APP_QUIET = "0""#
+        );
+        assert_eq!(
+            Verbosity::<InfoLevel>::new(0, 3).powershell_export_string(None),
+            r#"$env:RUST_LOG = "off""#
+        );
+    }
+
+    #[test]
+    fn format_args() {
+        use clap::Parser;
+
+        #[derive(Debug, Parser)]
+        struct Cli {
+            #[command(flatten)]
+            verbose: Verbosity,
+        }
+
+        let cli = Cli::parse_from(["cli", "-vv"]);
+        assert_eq!(
+            format!("{}", cli.verbose.format_args()),
+            "verbose=2 quiet=0 level=info"
+        );
+
+        let cli = Cli::parse_from(["cli", "-q"]);
+        assert_eq!(
+            format!("[{}]", cli.verbose.format_args()),
+            "[verbose=0 quiet=1 level=off]"
+        );
+    }
+
+    #[test]
+    fn to_diagnostic() {
+        let verbosity = Verbosity::<ErrorLevel>::new(2, 0);
+        let diagnostic = verbosity.to_diagnostic();
+        for field in [
+            "verbose=2",
+            "quiet=0",
+            "default=error",
+            "effective=info",
+            "silent=false",
+        ] {
+            assert!(diagnostic.contains(field), "{diagnostic}");
+        }
+        assert!(diagnostic.contains(&format!("effective={}", verbosity.level_name())));
+        #[cfg(all(feature = "log", feature = "tracing"))]
+        assert!(diagnostic.contains("[log and tracing features]"));
+
+        let diagnostic = Verbosity::<InfoLevel>::new(0, 3).to_diagnostic();
+        assert!(diagnostic.starts_with("verbose=0 quiet=3 default=info effective=off "));
+        assert!(diagnostic.ends_with(" silent=true"));
+    }
+
+    #[test]
+    #[cfg(any(feature = "log", feature = "tracing"))]
+    fn max_quiet_steps() {
+        #[derive(Copy, Clone, Debug, Default)]
+        struct NeverSilentLevel;
+
+        impl LogLevel for NeverSilentLevel {
+            #[cfg(feature = "log")]
+            fn default_log() -> Option<log::Level> {
+                Some(log::Level::Info)
+            }
+
+            #[cfg(feature = "tracing")]
+            fn default_tracing() -> Option<LevelFilter> {
+                Some(LevelFilter::INFO)
+            }
+
+            fn max_quiet_steps() -> u8 {
+                2
+            }
+        }
+
+        #[derive(Debug, clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            verbose: Verbosity<NeverSilentLevel>,
+        }
+
+        use clap::Parser;
+        let qq = Cli::parse_from(["cli", "-qq"]).verbose;
+        let qqqqq = Cli::parse_from(["cli", "-qqqqq"]).verbose;
+        assert_eq!(qqqqq.quiet_count(), 5);
+        assert_eq!(qqqqq.as_i8(), qq.as_i8());
+        assert_eq!(qqqqq.level_name(), "error");
+        assert!(!qqqqq.is_silent());
+        #[cfg(feature = "log")]
+        assert_eq!(qqqqq.log_level(), Some(log::Level::Error));
+        #[cfg(feature = "tracing")]
+        assert_eq!(qqqqq.tracing_level_filter(), LevelFilter::ERROR);
+
+        assert_eq!(Cli::parse_from(["cli", "-q"]).verbose.level_name(), "warn");
+    }
+
+    #[test]
+    fn saturating_add() {
+        let mut verbosity = Verbosity::<InfoLevel>::new(0, 0);
+        verbosity.saturating_add_verbose(1);
+        assert_eq!(verbosity.verbose_count(), 1);
+        verbosity.saturating_add_verbose(u8::MAX);
+        assert_eq!(verbosity.verbose_count(), 2);
+        assert!(verbosity.is_at_max());
+
+        let mut verbosity = Verbosity::<InfoLevel>::new(0, 0);
+        verbosity.saturating_add_quiet(10);
+        assert_eq!(verbosity.quiet_count(), 3);
+        assert!(verbosity.is_at_min());
+
+        let verbosity = Verbosity::<ErrorLevel>::new(0, 0);
+        assert_eq!(verbosity.with_saturating_add_verbose(3).verbose_count(), 3);
+        assert_eq!(verbosity.with_saturating_add_verbose(5).verbose_count(), 4);
+        assert_eq!(verbosity.with_saturating_add_quiet(5).quiet_count(), 1);
+        assert_eq!(verbosity.verbose_count(), 0);
+
+        // The other count is left alone
+        let verbosity = Verbosity::<ErrorLevel>::new(0, 1).with_saturating_add_verbose(10);
+        assert_eq!((verbosity.verbose_count(), verbosity.quiet_count()), (4, 1));
+
+        // Doesn't lower counts that are already past the limit
+        let verbosity = Verbosity::<ErrorLevel>::new(9, 0).with_saturating_add_verbose(1);
+        assert_eq!(verbosity.verbose_count(), 9);
+
+        let verbosity = Verbosity::<DoubleStepLevel>::new(0, 0).with_saturating_add_verbose(10);
+        assert_eq!(verbosity.verbose_count(), 2);
+        assert!(verbosity.is_at_max());
+    }
+
+    #[test]
+    fn checked_increment_decrement() {
+        for verbosity in -1..=4 {
+            let v = Verbosity::<ErrorLevel>::from_verbosity(verbosity);
+            match v.checked_increment() {
+                Some(more) => {
+                    assert!(verbosity < 4);
+                    assert_eq!(more.as_i8(), verbosity + 1);
+                }
+                None => assert_eq!(verbosity, 4),
+            }
+            match v.checked_decrement() {
+                Some(less) => {
+                    assert!(-1 < verbosity);
+                    assert_eq!(less.as_i8(), verbosity - 1);
+                }
+                None => assert_eq!(verbosity, -1),
+            }
+            assert_eq!(v.as_i8(), verbosity);
+        }
+    }
+
+    #[test]
+    fn bitor_bitand() {
+        let v = |verbose, quiet| Verbosity::<InfoLevel>::new(verbose, quiet);
+
+        assert_eq!((v(2, 0) | v(0, 1)).verbose_count(), 2);
+        assert_eq!((v(2, 0) & v(0, 1)).quiet_count(), 1);
+
+        // Counts aren't blended, and ties keep the left side
+        let either = v(1, 1) | v(0, 0);
+        assert_eq!((either.verbose_count(), either.quiet_count()), (1, 1));
+        let both = v(0, 0) & v(1, 1);
+        assert_eq!((both.verbose_count(), both.quiet_count()), (0, 0));
+
+        let mut assigned = v(0, 0);
+        assigned |= v(0, 2);
+        assert_eq!(assigned.level_name(), "info");
+        assigned |= v(1, 0);
+        assert_eq!(assigned.level_name(), "debug");
+        assigned &= v(0, 1);
+        assert_eq!(assigned.level_name(), "warn");
+        assigned &= v(3, 0);
+        assert_eq!(assigned.level_name(), "warn");
+
+        let all = [v(0, 3), v(0, 1), v(0, 0), v(1, 0), v(2, 0)];
+        for a in &all {
+            assert_eq!(a.clone() | a.clone(), *a);
+            assert_eq!(a.clone() & a.clone(), *a);
+            for b in &all {
+                assert_eq!(a.clone() | b.clone(), b.clone() | a.clone());
+                assert_eq!(a.clone() & b.clone(), b.clone() & a.clone());
+                for c in &all {
+                    assert_eq!(
+                        (a.clone() | b.clone()) | c.clone(),
+                        a.clone() | (b.clone() | c.clone())
+                    );
+                    assert_eq!(
+                        (a.clone() & b.clone()) & c.clone(),
+                        a.clone() & (b.clone() & c.clone())
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn neg() {
+        let negated = -Verbosity::<InfoLevel>::new(3, 0);
+        assert_eq!((negated.verbose_count(), negated.quiet_count()), (0, 3));
+        let negated = -Verbosity::<InfoLevel>::new(1, 3);
+        assert_eq!((negated.verbose_count(), negated.quiet_count()), (3, 1));
+        assert!((-Verbosity::<InfoLevel>::new(0, 0)).is_default());
+
+        for (verbose, quiet) in [(0, 0), (1, 0), (0, 2), (4, 1), (u8::MAX, 0)] {
+            let v = Verbosity::<InfoLevel>::new(verbose, quiet);
+            let sum = v.clone() + -v;
+            assert_eq!(sum.as_i8(), Verbosity::<InfoLevel>::default_verbosity());
+        }
+    }
+
+    #[test]
+    fn from_iter_extend() {
+        let v = |verbose, quiet| Verbosity::<InfoLevel>::new(verbose, quiet);
+
+        assert!(std::iter::empty()
+            .collect::<Verbosity<InfoLevel>>()
+            .is_default());
+        let single = [v(2, 1)].into_iter().collect::<Verbosity<_>>();
+        assert_eq!((single.verbose_count(), single.quiet_count()), (2, 1));
+
+        let mixed = [v(1, 0), v(0, 2), v(u8::MAX, 0)]
+            .into_iter()
+            .collect::<Verbosity<_>>();
+        assert_eq!((mixed.verbose_count(), mixed.quiet_count()), (u8::MAX, 2));
+
+        let mut extended = v(1, 0);
+        extended.extend([v(1, 0), v(0, 1)]);
+        assert_eq!((extended.verbose_count(), extended.quiet_count()), (2, 1));
+    }
+
+    #[test]
+    fn collect_max() {
+        let v = |verbose, quiet| Verbosity::<InfoLevel>::new(verbose, quiet);
+
+        assert!(super::collect_max(std::iter::empty::<Verbosity<InfoLevel>>()).is_default());
+        let max = super::collect_max([v(0, 1), v(2, 0), v(1, 0)]);
+        assert_eq!(max.verbose_count(), 2);
+        let max = super::collect_max([v(0, 3)]);
+        assert_eq!(max.quiet_count(), 3);
+    }
+
+    #[test]
+    #[cfg(feature = "log")]
+    fn log_enabled() {
+        for verbosity in -1..=4 {
+            let v = Verbosity::<ErrorLevel>::from_verbosity(verbosity);
+            for level in Verbosity::levels().flatten() {
+                assert_eq!(
+                    v.log_enabled(level, "app"),
+                    level <= v.log_level_filter(),
+                    "{v} {level}"
+                );
+                assert_eq!(v.log_enabled(level, "app"), v.is_at_least(level));
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(any(feature = "log", feature = "tracing"))]
+    fn custom_level_names() {
+        #[derive(Copy, Clone, Debug, Default)]
+        struct BuildLevel;
+
+        impl LogLevel for BuildLevel {
+            #[cfg(feature = "log")]
+            fn default_log() -> Option<log::Level> {
+                Some(log::Level::Info)
+            }
+
+            #[cfg(feature = "tracing")]
+            fn default_tracing() -> Option<LevelFilter> {
+                Some(LevelFilter::INFO)
+            }
+
+            fn level_names() -> &'static [&'static str; LEVEL_COUNT] {
+                &["none", "fatal", "warning", "status", "verbose", "trace"]
+            }
+        }
+
+        assert_eq!(Verbosity::<BuildLevel>::new(0, 0).to_string(), "status");
+        assert_eq!(Verbosity::<BuildLevel>::new(1, 0).level_name(), "verbose");
+        assert_eq!(Verbosity::<BuildLevel>::silent().to_string(), "none");
+        assert_eq!(BuildLevel::label(), "status");
+        assert_eq!(
+            BuildLevel::completion_values(),
+            ["fatal", "warning", "status", "verbose", "trace"]
+        );
+
+        let diagnostic = Verbosity::<BuildLevel>::new(0, 1).to_diagnostic();
+        assert!(
+            diagnostic.contains("default=status effective=warning"),
+            "{diagnostic}"
+        );
+        // The underlying level is unchanged
+        #[cfg(feature = "log")]
+        assert_eq!(
+            Verbosity::<BuildLevel>::new(0, 1).log_level(),
+            Some(log::Level::Warn)
+        );
+        assert_eq!(
+            Verbosity::<BuildLevel>::new(0, 1).to_env_logger_filter_string(),
+            "warn"
+        );
+    }
+
+    #[test]
+    fn filter_string_level() {
+        assert_eq!(super::filter_string_level(""), None);
+        assert_eq!(super::filter_string_level("my_crate=info"), None);
+        assert_eq!(
+            super::filter_string_level("my_crate=info,hyper=warn,Debug"),
+            Some(3)
+        );
+        assert_eq!(super::filter_string_level("off, my_crate=trace"), Some(-1));
+        assert_eq!(super::filter_string_level("info,warn"), Some(1));
+        assert_eq!(super::filter_string_level("my_crate"), None);
+    }
+
+    #[test]
+    #[cfg(any(feature = "log", feature = "tracing"))]
+    fn default_filter_string() {
+        #[derive(Copy, Clone, Debug, Default)]
+        struct FilterLevel;
+
+        impl LogLevel for FilterLevel {
+            #[cfg(feature = "log")]
+            fn default_log() -> Option<log::Level> {
+                Some(log::Level::Error)
+            }
+
+            #[cfg(feature = "tracing")]
+            fn default_tracing() -> Option<LevelFilter> {
+                Some(LevelFilter::ERROR)
+            }
+
+            fn default_filter_string() -> &'static str {
+                "my_crate=trace,info,hyper=warn"
+            }
+        }
+
+        #[derive(Copy, Clone, Debug, Default)]
+        struct ModulesOnlyLevel;
+
+        impl LogLevel for ModulesOnlyLevel {
+            #[cfg(feature = "log")]
+            fn default_log() -> Option<log::Level> {
+                Some(log::Level::Warn)
+            }
+
+            #[cfg(feature = "tracing")]
+            fn default_tracing() -> Option<LevelFilter> {
+                Some(LevelFilter::WARN)
+            }
+
+            fn default_filter_string() -> &'static str {
+                "my_crate=trace"
+            }
+        }
+
+        let verbosity = Verbosity::<FilterLevel>::new(0, 0);
+        assert_eq!(verbosity.level_name(), "info");
+        assert_eq!(FilterLevel::label(), "info");
+        #[cfg(feature = "log")]
+        assert_eq!(verbosity.log_level(), Some(log::Level::Info));
+        #[cfg(feature = "tracing")]
+        assert_eq!(verbosity.tracing_level_filter(), LevelFilter::INFO);
+        assert_eq!(Verbosity::<FilterLevel>::new(1, 0).level_name(), "debug");
+
+        assert_eq!(
+            Verbosity::<ModulesOnlyLevel>::new(0, 0).level_name(),
+            "warn"
+        );
     }
 }
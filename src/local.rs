@@ -0,0 +1,139 @@
+use crate::ErrorLevel;
+use crate::LogLevel;
+use crate::Verbosity;
+
+/// [`Verbosity`] whose flags are local to the command they are flattened into
+///
+/// [`Verbosity`] marks `--verbose` and `--quiet` as [global][clap::Arg::global], so they are
+/// propagated to and accepted by every subcommand.  With `LocalVerbosity`, the flags are only
+/// accepted directly on the command that flattens it, letting each subcommand have its own
+/// verbosity, or none at all.
+///
+/// ```rust,no_run
+/// # use clap::Parser;
+/// use clap_verbosity_flag::LocalVerbosity;
+///
+/// /// Le CLI
+/// #[derive(Debug, Parser)]
+/// struct Cli {
+///     #[command(subcommand)]
+///     command: Command,
+/// }
+///
+/// #[derive(Debug, clap::Subcommand)]
+/// enum Command {
+///     /// Accepts `build -v`
+///     Build {
+///         #[command(flatten)]
+///         verbose: LocalVerbosity,
+///     },
+///     /// Rejects `clean -v`
+///     Clean,
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct LocalVerbosity<L: LogLevel = ErrorLevel> {
+    inner: Verbosity<L>,
+}
+
+impl<L: LogLevel> LocalVerbosity<L> {
+    /// Create a new verbosity instance by explicitly setting the values
+    pub fn new(verbose: u8, quiet: u8) -> Self {
+        LocalVerbosity {
+            inner: Verbosity::new(verbose, quiet),
+        }
+    }
+
+    /// Unwrap the underlying [`Verbosity`]
+    pub fn into_inner(self) -> Verbosity<L> {
+        self.inner
+    }
+}
+
+impl<L: LogLevel> std::ops::Deref for LocalVerbosity<L> {
+    type Target = Verbosity<L>;
+
+    fn deref(&self) -> &Verbosity<L> {
+        &self.inner
+    }
+}
+
+impl<L: LogLevel> From<LocalVerbosity<L>> for Verbosity<L> {
+    fn from(verbosity: LocalVerbosity<L>) -> Self {
+        verbosity.inner
+    }
+}
+
+impl<L: LogLevel> clap::FromArgMatches for LocalVerbosity<L> {
+    fn from_arg_matches(matches: &clap::ArgMatches) -> Result<Self, clap::Error> {
+        let mut verbosity = Self::new(0, 0);
+        verbosity.update_from_arg_matches(matches)?;
+        Ok(verbosity)
+    }
+
+    fn update_from_arg_matches(&mut self, matches: &clap::ArgMatches) -> Result<(), clap::Error> {
+        self.inner = Verbosity::new(
+            matches.get_count(crate::VERBOSE),
+            matches.get_count(crate::QUIET),
+        )
+        .with_level_env();
+        Ok(())
+    }
+}
+
+impl<L: LogLevel> clap::Args for LocalVerbosity<L> {
+    fn augment_args(cmd: clap::Command) -> clap::Command {
+        cmd.arg(crate::verbose_arg::<L>().global(false))
+            .arg(crate::quiet_arg::<L>().global(false))
+    }
+
+    fn augment_args_for_update(cmd: clap::Command) -> clap::Command {
+        Self::augment_args(cmd)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Debug, Parser)]
+    struct Cli {
+        #[command(flatten)]
+        verbose: LocalVerbosity,
+
+        #[command(subcommand)]
+        command: Option<Command>,
+    }
+
+    #[derive(Debug, clap::Subcommand)]
+    enum Command {
+        Build {
+            #[command(flatten)]
+            verbose: LocalVerbosity,
+        },
+        Clean,
+    }
+
+    #[test]
+    fn verify_app() {
+        use clap::CommandFactory;
+        Cli::command().debug_assert()
+    }
+
+    #[test]
+    fn per_subcommand() {
+        let cli = Cli::parse_from(["cli", "-v", "build", "-qq"]);
+        assert_eq!(cli.verbose.as_i8(), 1);
+        match cli.command {
+            Some(Command::Build { verbose }) => assert_eq!(verbose.quiet_count(), 2),
+            command => panic!("unexpected {command:?}"),
+        }
+
+        let cli = Cli::parse_from(["cli", "-q", "clean"]);
+        assert_eq!(cli.verbose.as_i8(), -1);
+
+        assert!(Cli::try_parse_from(["cli", "clean", "-v"]).is_err());
+        assert!(Cli::try_parse_from(["cli", "build", "-v", "-q"]).is_err());
+    }
+}
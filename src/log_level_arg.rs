@@ -0,0 +1,255 @@
+use crate::ErrorLevel;
+use crate::LogLevel;
+use crate::Verbosity;
+use crate::LEVEL_NAMES;
+
+/// [`Verbosity`] that also accepts a level by name, like `--log-level=debug`
+///
+/// The accepted names are `off`, `error`, `warn`, `info`, `debug`, and `trace`, ignoring case.
+/// When `--log-level` is passed, it takes precedence over `--verbose` and `--quiet`.
+///
+/// ```rust,no_run
+/// # use clap::Parser;
+/// use clap_verbosity_flag::LogLevelArg;
+///
+/// /// Le CLI
+/// #[derive(Debug, Parser)]
+/// struct Cli {
+///     #[command(flatten)]
+///     verbose: LogLevelArg,
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct LogLevelArg<L: LogLevel = ErrorLevel> {
+    inner: Verbosity<L>,
+    level: Option<&'static str>,
+}
+
+impl<L: LogLevel> LogLevelArg<L> {
+    /// Create a new verbosity instance by explicitly setting the values
+    pub fn new(verbose: u8, quiet: u8) -> Self {
+        LogLevelArg {
+            inner: Verbosity::new(verbose, quiet),
+            level: None,
+        }
+    }
+
+    /// The level passed to `--log-level`, if any
+    pub fn log_level_arg(&self) -> Option<&str> {
+        self.level
+    }
+
+    /// Unwrap the underlying [`Verbosity`]
+    pub fn into_inner(self) -> Verbosity<L> {
+        self.inner
+    }
+}
+
+impl<L: LogLevel> std::ops::Deref for LogLevelArg<L> {
+    type Target = Verbosity<L>;
+
+    fn deref(&self) -> &Verbosity<L> {
+        &self.inner
+    }
+}
+
+impl<L: LogLevel> From<LogLevelArg<L>> for Verbosity<L> {
+    fn from(verbosity: LogLevelArg<L>) -> Self {
+        verbosity.inner
+    }
+}
+
+impl<L: LogLevel> clap::FromArgMatches for LogLevelArg<L> {
+    fn from_arg_matches(matches: &clap::ArgMatches) -> Result<Self, clap::Error> {
+        let mut verbosity = Self::new(0, 0);
+        verbosity.update_from_arg_matches(matches)?;
+        Ok(verbosity)
+    }
+
+    fn update_from_arg_matches(&mut self, matches: &clap::ArgMatches) -> Result<(), clap::Error> {
+        self.level = matches.get_one::<&'static str>(LOG_LEVEL).copied();
+        self.inner = match self.level.and_then(crate::level_value_name) {
+            Some(verbosity) => Verbosity::from_verbosity(verbosity),
+            None => Verbosity::new(
+                matches.get_count(crate::VERBOSE),
+                matches.get_count(crate::QUIET),
+            )
+            .with_level_env(),
+        };
+        Ok(())
+    }
+}
+
+impl<L: LogLevel> clap::Args for LogLevelArg<L> {
+    fn augment_args(cmd: clap::Command) -> clap::Command {
+        cmd.arg(crate::verbose_arg::<L>())
+            .arg(crate::quiet_arg::<L>())
+            .arg(
+                clap::Arg::new(LOG_LEVEL)
+                    .long("log-level")
+                    .short('L')
+                    .value_name("LEVEL")
+                    .value_parser(LevelValueParser {
+                        names: L::level_names(),
+                        completions: L::completion_values(),
+                    })
+                    .ignore_case(true)
+                    .global(true)
+                    .help("Set the log level, overriding --verbose and --quiet"),
+            )
+    }
+
+    fn augment_args_for_update(cmd: clap::Command) -> clap::Command {
+        Self::augment_args(cmd)
+    }
+}
+
+const LOG_LEVEL: &str = "log_level";
+
+/// Parse a level name, standard or from [`LogLevel::level_names`], into its canonical, lowercase
+/// form
+#[derive(Copy, Clone, Debug)]
+struct LevelValueParser {
+    names: &'static [&'static str; 6],
+    completions: &'static [&'static str],
+}
+
+impl clap::builder::TypedValueParser for LevelValueParser {
+    type Value = &'static str;
+
+    fn parse_ref(
+        &self,
+        cmd: &clap::Command,
+        arg: Option<&clap::Arg>,
+        value: &std::ffi::OsStr,
+    ) -> Result<Self::Value, clap::Error> {
+        let mut accepted = LEVEL_NAMES.to_vec();
+        accepted.extend(self.names.iter().filter(|name| !LEVEL_NAMES.contains(name)));
+        let value =
+            clap::builder::PossibleValuesParser::new(accepted).parse_ref(cmd, arg, value)?;
+        let index = self
+            .names
+            .iter()
+            .position(|name| name.eq_ignore_ascii_case(&value))
+            .or_else(|| {
+                LEVEL_NAMES
+                    .iter()
+                    .position(|name| name.eq_ignore_ascii_case(&value))
+            })
+            .expect("PossibleValuesParser only accepts level names");
+        Ok(LEVEL_NAMES[index])
+    }
+
+    fn possible_values(
+        &self,
+    ) -> Option<Box<dyn Iterator<Item = clap::builder::PossibleValue> + '_>> {
+        Some(Box::new(
+            self.completions
+                .iter()
+                .copied()
+                .map(clap::builder::PossibleValue::new),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Debug, Parser)]
+    struct Cli {
+        #[command(flatten)]
+        verbose: LogLevelArg,
+    }
+
+    #[test]
+    fn verify_app() {
+        use clap::CommandFactory;
+        Cli::command().debug_assert()
+    }
+
+    #[test]
+    fn level_names() {
+        for (name, verbosity) in LEVEL_NAMES.iter().zip(-1..) {
+            let cli = Cli::parse_from(["cli", "--log-level", name]);
+            assert_eq!(cli.verbose.log_level_arg(), Some(*name));
+            assert_eq!(cli.verbose.as_i8(), verbosity);
+        }
+
+        let cli = Cli::parse_from(["cli", "-L", "DeBuG"]);
+        assert_eq!(cli.verbose.log_level_arg(), Some("debug"));
+        assert_eq!(cli.verbose.as_i8(), 3);
+
+        assert!(Cli::try_parse_from(["cli", "--log-level", "loud"]).is_err());
+    }
+
+    #[test]
+    fn completion_values() {
+        use clap::CommandFactory;
+        let cmd = Cli::command();
+        let arg = cmd
+            .get_arguments()
+            .find(|arg| arg.get_id() == LOG_LEVEL)
+            .unwrap();
+        let values = arg
+            .get_possible_values()
+            .iter()
+            .map(|value| value.get_name().to_owned())
+            .collect::<Vec<_>>();
+        assert_eq!(values, ["error", "warn", "info", "debug", "trace"]);
+
+        // Not suggested but still accepted
+        let cli = Cli::parse_from(["cli", "--log-level=off"]);
+        assert!(cli.verbose.is_silent());
+    }
+
+    #[test]
+    fn log_level_takes_precedence() {
+        let cli = Cli::parse_from(["cli", "-vvv", "--log-level=warn"]);
+        assert_eq!(cli.verbose.as_i8(), 1);
+
+        let cli = Cli::parse_from(["cli", "-vv"]);
+        assert_eq!(cli.verbose.log_level_arg(), None);
+        assert_eq!(cli.verbose.as_i8(), 2);
+    }
+
+    #[test]
+    fn custom_level_names() {
+        #[derive(Copy, Clone, Debug, Default)]
+        struct BuildLevel;
+
+        impl LogLevel for BuildLevel {
+            #[cfg(feature = "log")]
+            fn default_log() -> Option<log::Level> {
+                Some(log::Level::Info)
+            }
+
+            #[cfg(feature = "tracing")]
+            fn default_tracing() -> Option<tracing_subscriber::filter::LevelFilter> {
+                Some(tracing_subscriber::filter::LevelFilter::INFO)
+            }
+
+            fn level_names() -> &'static [&'static str; 6] {
+                &["none", "fatal", "warning", "status", "verbose", "trace"]
+            }
+        }
+
+        #[derive(Debug, Parser)]
+        struct Cli {
+            #[command(flatten)]
+            verbose: LogLevelArg<BuildLevel>,
+        }
+
+        use clap::CommandFactory;
+        Cli::command().debug_assert();
+
+        let cli = Cli::parse_from(["cli", "--log-level=Verbose"]);
+        assert_eq!(cli.verbose.log_level_arg(), Some("debug"));
+        assert_eq!(cli.verbose.to_string(), "verbose");
+
+        // Standard names are still accepted
+        let cli = Cli::parse_from(["cli", "--log-level=warn"]);
+        assert_eq!(cli.verbose.level_name(), "warning");
+    }
+}
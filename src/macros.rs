@@ -0,0 +1,656 @@
+/// Declare a [`LogLevel`][crate::LogLevel] with the given default level
+///
+/// This declares a unit struct, deriving `Copy`, `Clone`, `Debug`, and `Default`, and implements
+/// [`LogLevel`][crate::LogLevel] for it.  The tracing default matches the log default unless it is
+/// given as a third argument.
+///
+/// ```rust
+/// use clap_verbosity_flag::{verbosity_level, Verbosity};
+///
+/// verbosity_level!(MyLevel, log::Level::Debug);
+///
+/// # #[cfg(feature = "log")]
+/// # fn main() {
+/// let verbosity = Verbosity::<MyLevel>::new(0, 0);
+/// assert_eq!(verbosity.log_level(), Some(log::Level::Debug));
+/// # }
+/// # #[cfg(not(feature = "log"))]
+/// # fn main() {}
+/// ```
+///
+/// Attributes and visibility are passed along to the struct:
+/// ```rust
+/// use clap_verbosity_flag::verbosity_level;
+///
+/// verbosity_level!(
+///     /// Show warnings by default
+///     pub(crate) QuietishLevel,
+///     log::Level::Warn
+/// );
+/// ```
+///
+/// With a different tracing default, used by
+/// [`Verbosity::tracing_level_filter`][crate::Verbosity::tracing_level_filter] when the `log`
+/// feature is disabled.  The log default is still required, so the level works with either:
+/// ```rust
+/// use clap_verbosity_flag::{verbosity_level, LogLevel};
+/// use tracing_subscriber::filter::LevelFilter;
+///
+/// verbosity_level!(MyLevel, log::Level::Info, LevelFilter::DEBUG);
+///
+/// # #[cfg(feature = "tracing")]
+/// # fn main() {
+/// assert_eq!(MyLevel::default_tracing(), Some(LevelFilter::DEBUG));
+/// # }
+/// # #[cfg(not(feature = "tracing"))]
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! verbosity_level {
+    (
+        @impl
+        $(#[$attr:meta])*
+        $vis:vis $name:ident,
+        $($level:ident)::+,
+        $tracing:expr
+    ) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone, Debug, Default)]
+        $vis struct $name;
+
+        impl $crate::LogLevel for $name {
+            $crate::__if_log! {
+                fn default_log() -> ::std::option::Option<$crate::__macro_support::Level> {
+                    ::std::option::Option::Some($($level)::+)
+                }
+            }
+
+            $crate::__if_tracing! {
+                fn default_tracing(
+                ) -> ::std::option::Option<$crate::__macro_support::LevelFilter> {
+                    $tracing
+                }
+            }
+
+            $crate::__if_no_backend! {
+                fn default_known_level() -> $crate::KnownLevel {
+                    $crate::__known_level!($($level)+)
+                }
+            }
+
+            fn verbose_help() -> ::std::option::Option<&'static str> {
+                ::std::option::Option::Some(::std::concat!(
+                    "More output per occurrence (default: ",
+                    $crate::__level_name!($($level)+),
+                    ")"
+                ))
+            }
+        }
+    };
+    (
+        $(#[$attr:meta])*
+        $vis:vis $name:ident,
+        $($level:ident)::+
+        $(,)?
+    ) => {
+        $crate::verbosity_level!(
+            @impl
+            $(#[$attr])*
+            $vis $name,
+            $($level)::+,
+            $crate::__tracing_level!($($level)+)
+        );
+    };
+    (
+        $(#[$attr:meta])*
+        $vis:vis $name:ident,
+        $($level:ident)::+,
+        $tracing:expr
+        $(,)?
+    ) => {
+        $crate::verbosity_level!(
+            @impl
+            $(#[$attr])*
+            $vis $name,
+            $($level)::+,
+            ::std::option::Option::Some($tracing)
+        );
+    };
+}
+
+/// Declare a [`LogLevel`][crate::LogLevel], setting any of its options
+///
+/// Like [`verbosity_level!`][crate::verbosity_level], this declares a unit struct, deriving
+/// `Copy`, `Clone`, `Debug`, and `Default`.  Levels are given by name: `off`, `error`, `warn`,
+/// `info`, `debug`, or `trace`.  After `default`, the options may be in any order:
+///
+/// | Option          | Value           | [`LogLevel`][crate::LogLevel] method                |
+/// |-----------------|-----------------|-----------------------------------------------------|
+/// | `max`           | level           | `max_level` and `max_tracing_level`                 |
+/// | `min`           | level           | `min_level` and `min_tracing_level`                 |
+/// | `verbose_short` | `char`          | `verbose_short_char`                                |
+/// | `quiet_short`   | `char`          | `quiet_short_char`                                  |
+/// | `verbose_long`  | `&str`          | `verbose_long_name`                                 |
+/// | `quiet_long`    | `&str`          | `quiet_long_name`                                   |
+/// | `verbose_help`  | `&str`          | `verbose_help`                                      |
+/// | `quiet_help`    | `&str`          | `quiet_help`                                        |
+/// | `verbose_env`   | `&str`          | `verbose_env_var_name`                              |
+/// | `quiet_env`     | `&str`          | `quiet_env_var_name`                                |
+/// | `env_prefix`    | `&str`          | `env_var_prefix`                                    |
+/// | `env_var`       | `&str`          | `env_var`                                           |
+/// | `step_size`     | `u8`            | `step_size`                                         |
+/// | `conflicts`     | `bool`          | `verbose_conflicts_with_quiet`                      |
+///
+/// ```rust
+/// use clap_verbosity_flag::{impl_log_level, LogLevel, Verbosity};
+///
+/// impl_log_level!(
+///     /// Debug output with `-d`
+///     pub MyLevel,
+///     default: info,
+///     max: debug,
+///     verbose_short: 'd',
+///     quiet_short: 's',
+///     verbose_env: "MY_VERBOSE",
+/// );
+///
+/// assert_eq!(MyLevel::verbose_short_char(), Some('d'));
+/// # #[cfg(feature = "log")]
+/// # {
+/// let verbosity = Verbosity::<MyLevel>::new(3, 0);
+/// assert_eq!(verbosity.log_level(), Some(log::Level::Debug));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! impl_log_level {
+    (
+        $(#[$attr:meta])*
+        $vis:vis $name:ident,
+        default: $default:ident
+        $(, $key:ident : $value:tt)*
+        $(,)?
+    ) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone, Debug, Default)]
+        $vis struct $name;
+
+        impl $crate::LogLevel for $name {
+            $crate::__if_log! {
+                fn default_log() -> ::std::option::Option<$crate::__macro_support::Level> {
+                    $crate::__log_level_named!($default)
+                }
+            }
+
+            $crate::__if_tracing! {
+                fn default_tracing(
+                ) -> ::std::option::Option<$crate::__macro_support::LevelFilter> {
+                    ::std::option::Option::Some($crate::__tracing_level_named!($default))
+                }
+            }
+
+            $crate::__if_no_backend! {
+                fn default_known_level() -> $crate::KnownLevel {
+                    $crate::__known_level_named!($default)
+                }
+            }
+
+            $($crate::__impl_log_level_option!($key: $value);)*
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_log_level_option {
+    (max: $level:ident) => {
+        $crate::__if_log! {
+            fn max_level() -> ::std::option::Option<$crate::__macro_support::Level> {
+                $crate::__log_level_named!($level)
+            }
+        }
+
+        $crate::__if_tracing! {
+            fn max_tracing_level() -> $crate::__macro_support::LevelFilter {
+                $crate::__tracing_level_named!($level)
+            }
+        }
+
+        $crate::__if_no_backend! {
+            fn max_known_level() -> $crate::KnownLevel {
+                $crate::__known_level_named!($level)
+            }
+        }
+    };
+    (min: $level:ident) => {
+        $crate::__if_log! {
+            fn min_level() -> ::std::option::Option<$crate::__macro_support::Level> {
+                $crate::__log_level_named!($level)
+            }
+        }
+
+        $crate::__if_tracing! {
+            fn min_tracing_level() -> $crate::__macro_support::LevelFilter {
+                $crate::__tracing_level_named!($level)
+            }
+        }
+
+        $crate::__if_no_backend! {
+            fn min_known_level() -> $crate::KnownLevel {
+                $crate::__known_level_named!($level)
+            }
+        }
+    };
+    (verbose_short: $short:literal) => {
+        fn verbose_short_char() -> ::std::option::Option<char> {
+            ::std::option::Option::Some($short)
+        }
+    };
+    (quiet_short: $short:literal) => {
+        fn quiet_short_char() -> ::std::option::Option<char> {
+            ::std::option::Option::Some($short)
+        }
+    };
+    (verbose_long: $long:literal) => {
+        fn verbose_long_name() -> &'static str {
+            $long
+        }
+    };
+    (quiet_long: $long:literal) => {
+        fn quiet_long_name() -> &'static str {
+            $long
+        }
+    };
+    (verbose_help: $help:literal) => {
+        fn verbose_help() -> ::std::option::Option<&'static str> {
+            ::std::option::Option::Some($help)
+        }
+    };
+    (quiet_help: $help:literal) => {
+        fn quiet_help() -> ::std::option::Option<&'static str> {
+            ::std::option::Option::Some($help)
+        }
+    };
+    (verbose_env: $var:literal) => {
+        fn verbose_env_var_name() -> ::std::option::Option<&'static str> {
+            ::std::option::Option::Some($var)
+        }
+    };
+    (quiet_env: $var:literal) => {
+        fn quiet_env_var_name() -> ::std::option::Option<&'static str> {
+            ::std::option::Option::Some($var)
+        }
+    };
+    (env_prefix: $prefix:literal) => {
+        fn env_var_prefix() -> ::std::option::Option<&'static str> {
+            ::std::option::Option::Some($prefix)
+        }
+    };
+    (env_var: $var:literal) => {
+        fn env_var() -> ::std::option::Option<&'static str> {
+            ::std::option::Option::Some($var)
+        }
+    };
+    (step_size: $step:literal) => {
+        fn step_size() -> u8 {
+            $step
+        }
+    };
+    (conflicts: $conflicts:literal) => {
+        fn verbose_conflicts_with_quiet() -> bool {
+            $conflicts
+        }
+    };
+    ($key:ident: $value:tt) => {
+        ::std::compile_error!(::std::concat!(
+            "unknown `impl_log_level!` option `",
+            ::std::stringify!($key),
+            "`"
+        ));
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __log_level_named {
+    (off) => {
+        ::std::option::Option::None
+    };
+    (error) => {
+        ::std::option::Option::Some($crate::__macro_support::Level::Error)
+    };
+    (warn) => {
+        ::std::option::Option::Some($crate::__macro_support::Level::Warn)
+    };
+    (info) => {
+        ::std::option::Option::Some($crate::__macro_support::Level::Info)
+    };
+    (debug) => {
+        ::std::option::Option::Some($crate::__macro_support::Level::Debug)
+    };
+    (trace) => {
+        ::std::option::Option::Some($crate::__macro_support::Level::Trace)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tracing_level_named {
+    (off) => {
+        $crate::__macro_support::LevelFilter::OFF
+    };
+    (error) => {
+        $crate::__macro_support::LevelFilter::ERROR
+    };
+    (warn) => {
+        $crate::__macro_support::LevelFilter::WARN
+    };
+    (info) => {
+        $crate::__macro_support::LevelFilter::INFO
+    };
+    (debug) => {
+        $crate::__macro_support::LevelFilter::DEBUG
+    };
+    (trace) => {
+        $crate::__macro_support::LevelFilter::TRACE
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __known_level_named {
+    (off) => {
+        $crate::KnownLevel::Off
+    };
+    (error) => {
+        $crate::KnownLevel::Error
+    };
+    (warn) => {
+        $crate::KnownLevel::Warn
+    };
+    (info) => {
+        $crate::KnownLevel::Info
+    };
+    (debug) => {
+        $crate::KnownLevel::Debug
+    };
+    (trace) => {
+        $crate::KnownLevel::Trace
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tracing_level {
+    (Error) => {
+        ::std::option::Option::Some($crate::__macro_support::LevelFilter::ERROR)
+    };
+    (Warn) => {
+        ::std::option::Option::Some($crate::__macro_support::LevelFilter::WARN)
+    };
+    (Info) => {
+        ::std::option::Option::Some($crate::__macro_support::LevelFilter::INFO)
+    };
+    (Debug) => {
+        ::std::option::Option::Some($crate::__macro_support::LevelFilter::DEBUG)
+    };
+    (Trace) => {
+        ::std::option::Option::Some($crate::__macro_support::LevelFilter::TRACE)
+    };
+    ($_path:ident $($rest:ident)+) => {
+        $crate::__tracing_level!($($rest)+)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __known_level {
+    (Error) => {
+        $crate::KnownLevel::Error
+    };
+    (Warn) => {
+        $crate::KnownLevel::Warn
+    };
+    (Info) => {
+        $crate::KnownLevel::Info
+    };
+    (Debug) => {
+        $crate::KnownLevel::Debug
+    };
+    (Trace) => {
+        $crate::KnownLevel::Trace
+    };
+    ($_path:ident $($rest:ident)+) => {
+        $crate::__known_level!($($rest)+)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __level_name {
+    (Error) => {
+        "error"
+    };
+    (Warn) => {
+        "warn"
+    };
+    (Info) => {
+        "info"
+    };
+    (Debug) => {
+        "debug"
+    };
+    (Trace) => {
+        "trace"
+    };
+    ($_path:ident $($rest:ident)+) => {
+        $crate::__level_name!($($rest)+)
+    };
+}
+
+#[cfg(feature = "log")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __if_log {
+    ($($item:tt)*) => {
+        $($item)*
+    };
+}
+
+#[cfg(not(feature = "log"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __if_log {
+    ($($item:tt)*) => {};
+}
+
+#[cfg(feature = "tracing")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __if_tracing {
+    ($($item:tt)*) => {
+        $($item)*
+    };
+}
+
+#[cfg(not(feature = "tracing"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __if_tracing {
+    ($($item:tt)*) => {};
+}
+
+#[cfg(not(any(feature = "log", feature = "tracing")))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __if_no_backend {
+    ($($item:tt)*) => {
+        $($item)*
+    };
+}
+
+#[cfg(any(feature = "log", feature = "tracing"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __if_no_backend {
+    ($($item:tt)*) => {};
+}
+
+#[cfg(test)]
+mod test {
+    use clap::Parser;
+
+    crate::verbosity_level!(MacroLevel, log::Level::Debug);
+
+    #[cfg(any(feature = "log", feature = "tracing"))]
+    crate::verbosity_level!(
+        /// Different log and tracing defaults
+        pub(crate) SplitLevel,
+        log::Level::Warn,
+        tracing_subscriber::filter::LevelFilter::TRACE,
+    );
+
+    #[derive(Debug, Parser)]
+    struct Cli {
+        #[command(flatten)]
+        verbose: crate::Verbosity<MacroLevel>,
+    }
+
+    #[test]
+    fn verify_app() {
+        use clap::CommandFactory;
+        Cli::command().debug_assert()
+    }
+
+    #[test]
+    fn help() {
+        use crate::LogLevel;
+        assert_eq!(
+            MacroLevel::verbose_help(),
+            Some("More output per occurrence (default: debug)")
+        );
+        assert_eq!(MacroLevel::quiet_help(), Some("Less output per occurrence"));
+    }
+
+    #[test]
+    #[cfg(feature = "log")]
+    fn log_default() {
+        let cli = Cli::parse_from(["cli", "-v"]);
+        assert_eq!(cli.verbose.log_level(), Some(log::Level::Trace));
+        assert_eq!(
+            crate::Verbosity::<SplitLevel>::new(0, 0).log_level(),
+            Some(log::Level::Warn)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "tracing")]
+    fn tracing_default() {
+        use crate::LogLevel;
+        use tracing_subscriber::filter::LevelFilter;
+
+        let cli = Cli::parse_from(["cli", "-q"]);
+        assert_eq!(cli.verbose.tracing_level_filter(), LevelFilter::INFO);
+        assert_eq!(MacroLevel::default_tracing(), Some(LevelFilter::DEBUG));
+        assert_eq!(SplitLevel::default_tracing(), Some(LevelFilter::TRACE));
+    }
+
+    crate::impl_log_level!(MyLevel, default: info);
+
+    #[derive(Debug, Parser)]
+    struct ImplCli {
+        #[command(flatten)]
+        verbose: crate::Verbosity<MyLevel>,
+    }
+
+    #[test]
+    fn impl_log_level_verify_app() {
+        use clap::CommandFactory;
+        ImplCli::command().debug_assert()
+    }
+
+    crate::impl_log_level!(
+        /// Every option
+        pub(crate) FullLevel,
+        default: warn,
+        quiet_short: 's',
+        max: debug,
+        min: error,
+        verbose_short: 'd',
+        verbose_long: "more",
+        quiet_long: "less",
+        verbose_help: "Show more",
+        quiet_help: "Show less",
+        verbose_env: "FULL_VERBOSE",
+        quiet_env: "FULL_QUIET",
+        env_var: "FULL_LOG",
+        step_size: 1,
+    );
+
+    #[test]
+    fn impl_log_level() {
+        use crate::LogLevel;
+
+        #[cfg(feature = "log")]
+        assert_eq!(MyLevel::default_log(), Some(log::Level::Info));
+        #[cfg(feature = "tracing")]
+        assert_eq!(
+            MyLevel::default_tracing(),
+            Some(tracing_subscriber::filter::LevelFilter::INFO)
+        );
+        assert_eq!(MyLevel::verbose_short_char(), Some('v'));
+    }
+
+    #[test]
+    #[cfg(not(any(feature = "log", feature = "tracing")))]
+    fn known_level_defaults() {
+        use crate::KnownLevel;
+        use crate::LogLevel;
+
+        assert_eq!(MacroLevel::default_known_level(), KnownLevel::Debug);
+        assert_eq!(MyLevel::default_known_level(), KnownLevel::Info);
+        assert_eq!(FullLevel::default_known_level(), KnownLevel::Warn);
+        assert_eq!(FullLevel::max_known_level(), KnownLevel::Debug);
+        assert_eq!(FullLevel::min_known_level(), KnownLevel::Error);
+        assert_eq!(MyLevel::label(), "info");
+    }
+
+    #[test]
+    fn impl_log_level_options() {
+        use crate::LogLevel;
+
+        #[derive(Debug, Parser)]
+        struct Cli {
+            #[command(flatten)]
+            verbose: crate::Verbosity<FullLevel>,
+        }
+
+        use clap::CommandFactory;
+        Cli::command().debug_assert();
+
+        assert_eq!(FullLevel::verbose_short_char(), Some('d'));
+        assert_eq!(FullLevel::quiet_short_char(), Some('s'));
+        assert_eq!(FullLevel::verbose_long_name(), "more");
+        assert_eq!(FullLevel::quiet_long_name(), "less");
+        assert_eq!(FullLevel::verbose_help(), Some("Show more"));
+        assert_eq!(FullLevel::quiet_help(), Some("Show less"));
+        assert_eq!(FullLevel::verbose_env_var_name(), Some("FULL_VERBOSE"));
+        assert_eq!(FullLevel::quiet_env_var_name(), Some("FULL_QUIET"));
+        assert_eq!(FullLevel::env_var(), Some("FULL_LOG"));
+        assert_eq!(FullLevel::env_var_prefix(), None);
+
+        let cli = Cli::parse_from(["cli", "-ddd"]);
+        assert_eq!(cli.verbose.level_name(), "debug");
+        assert!(cli.verbose.is_at_max());
+        #[cfg(feature = "log")]
+        {
+            assert_eq!(cli.verbose.log_level(), Some(log::Level::Debug));
+            let cli = Cli::parse_from(["cli", "--less", "--less"]);
+            assert_eq!(cli.verbose.log_level(), Some(log::Level::Error));
+        }
+        #[cfg(feature = "tracing")]
+        assert_eq!(
+            cli.verbose.tracing_level_filter(),
+            tracing_subscriber::filter::LevelFilter::DEBUG
+        );
+    }
+}
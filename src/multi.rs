@@ -0,0 +1,293 @@
+use crate::ErrorLevel;
+use crate::LogLevel;
+use crate::Verbosity;
+
+/// A separate [`Verbosity`] for each subsystem, like `--parser-verbose` and `--codegen-quiet`
+///
+/// Flatten this into your CLI and add each subsystem's flags with [`MultiVerbosity::register`]
+/// before parsing.  Flattening alone adds no flags, as the subsystems aren't known to the type.
+///
+/// ```rust
+/// use clap::{CommandFactory, FromArgMatches, Parser};
+/// use clap_verbosity_flag::{InfoLevel, MultiVerbosity};
+///
+/// /// Le CLI
+/// #[derive(Debug, Parser)]
+/// struct Cli {
+///     #[command(flatten)]
+///     verbose: MultiVerbosity<InfoLevel>,
+/// }
+///
+/// # #[cfg(feature = "log")]
+/// # fn main() {
+/// let cmd = MultiVerbosity::<InfoLevel>::register(Cli::command(), "parser");
+/// let cmd = MultiVerbosity::<InfoLevel>::register(cmd, "codegen");
+/// let matches = cmd.get_matches_from(["cli", "--parser-verbose"]);
+/// let cli = Cli::from_arg_matches(&matches).unwrap();
+/// assert_eq!(cli.verbose.get("parser").unwrap().log_level(), Some(log::Level::Debug));
+/// assert_eq!(cli.verbose.get("codegen").unwrap().log_level(), Some(log::Level::Info));
+/// # }
+/// # #[cfg(not(feature = "log"))]
+/// # fn main() {}
+/// ```
+#[derive(Debug, Clone)]
+pub struct MultiVerbosity<L: LogLevel = ErrorLevel> {
+    subsystems: Vec<(String, Verbosity<L>)>,
+}
+
+impl<L: LogLevel> MultiVerbosity<L> {
+    /// Add `--{name}-verbose` and `--{name}-quiet` flags to `cmd`
+    ///
+    /// The flags are named after [`LogLevel::verbose_long_name`] and
+    /// [`LogLevel::quiet_long_name`], with the help and aliases of `L`.  Short flags are left
+    /// out, as they would be the same for every subsystem.
+    pub fn register(cmd: clap::Command, name: &str) -> clap::Command {
+        let verbose = verbose_id::<L>(name);
+        let quiet = subsystem_arg(
+            crate::quiet_arg::<L>(),
+            name,
+            &quiet_id::<L>(name),
+            L::quiet_aliases(),
+        )
+        .conflicts_with(clap::builder::Resettable::Reset);
+        let quiet = if L::verbose_conflicts_with_quiet() {
+            quiet.conflicts_with(verbose.clone())
+        } else {
+            quiet
+        };
+        cmd.arg(subsystem_arg(
+            crate::verbose_arg::<L>(),
+            name,
+            &verbose,
+            L::verbose_aliases(),
+        ))
+        .arg(quiet)
+    }
+
+    /// Names of the parsed subsystems, in the order they were registered
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.subsystems.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// Get the verbosity of a subsystem, if it was registered
+    pub fn get(&self, name: &str) -> Option<&Verbosity<L>> {
+        self.subsystems
+            .iter()
+            .find(|(subsystem, _)| subsystem == name)
+            .map(|(_, verbosity)| verbosity)
+    }
+
+    /// Get the verbosity of a subsystem, falling back to `L`'s default
+    pub fn get_or_default(&self, name: &str) -> Verbosity<L> {
+        match self.get(name) {
+            Some(verbosity) => Verbosity::new(verbosity.verbose_count(), verbosity.quiet_count()),
+            None => Verbosity::new(0, 0),
+        }
+    }
+
+    fn set(&mut self, name: &str, verbosity: Verbosity<L>) {
+        match self
+            .subsystems
+            .iter_mut()
+            .find(|(subsystem, _)| subsystem == name)
+        {
+            Some((_, existing)) => *existing = verbosity,
+            None => self.subsystems.push((name.to_owned(), verbosity)),
+        }
+    }
+}
+
+fn verbose_id<L: LogLevel>(name: &str) -> String {
+    format!("{name}-{}", L::verbose_long_name())
+}
+
+fn quiet_id<L: LogLevel>(name: &str) -> String {
+    format!("{name}-{}", L::quiet_long_name())
+}
+
+/// Turn `arg`, from [`crate::verbose_arg`] or [`crate::quiet_arg`], into one for the subsystem
+/// `name`
+fn subsystem_arg(
+    arg: clap::Arg,
+    name: &str,
+    id: &str,
+    aliases: &'static [&'static str],
+) -> clap::Arg {
+    let help = arg.get_help().map(|help| format!("{help}, for {name}"));
+    let long_help = arg
+        .get_long_help()
+        .map(|help| format!("{help}\n\nThis is for {name}."));
+    let arg = aliases.iter().fold(
+        arg.id(id.to_owned())
+            .long(id.to_owned())
+            .short(None)
+            .alias(clap::builder::Resettable::Reset),
+        |arg, alias| arg.visible_alias(format!("{name}-{alias}")),
+    );
+    let arg = match help {
+        Some(help) => arg.help(help),
+        None => arg,
+    };
+    match long_help {
+        Some(long_help) => arg.long_help(long_help),
+        None => arg,
+    }
+}
+
+impl<L: LogLevel> Default for MultiVerbosity<L> {
+    fn default() -> Self {
+        MultiVerbosity {
+            subsystems: Vec::new(),
+        }
+    }
+}
+
+impl<L: LogLevel> clap::FromArgMatches for MultiVerbosity<L> {
+    fn from_arg_matches(matches: &clap::ArgMatches) -> Result<Self, clap::Error> {
+        let mut verbosity = Self::default();
+        verbosity.update_from_arg_matches(matches)?;
+        Ok(verbosity)
+    }
+
+    fn update_from_arg_matches(&mut self, matches: &clap::ArgMatches) -> Result<(), clap::Error> {
+        let count = |id: &str| matches.try_get_one::<u8>(id).ok().flatten().copied();
+        let suffix = format!("-{}", L::verbose_long_name());
+        for id in matches.ids() {
+            let name = match id.as_str().strip_suffix(&suffix) {
+                Some(name) => name,
+                None => continue,
+            };
+            // Skip unrelated arguments that happen to end in `-verbose`
+            if let (Some(verbose), Some(quiet)) = (count(id.as_str()), count(&quiet_id::<L>(name)))
+            {
+                self.set(name, Verbosity::new(verbose, quiet).with_level_env());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Adds no flags, see [`MultiVerbosity::register`]
+impl<L: LogLevel> clap::Args for MultiVerbosity<L> {
+    fn augment_args(cmd: clap::Command) -> clap::Command {
+        cmd
+    }
+
+    fn augment_args_for_update(cmd: clap::Command) -> clap::Command {
+        Self::augment_args(cmd)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use clap::CommandFactory;
+    use clap::FromArgMatches;
+    use clap::Parser;
+
+    use super::*;
+
+    crate::impl_log_level!(
+        CompilerLevel,
+        default: info,
+        verbose_long: "more",
+        quiet_help: "Less output",
+    );
+
+    #[derive(Debug, Parser)]
+    struct Cli {
+        #[arg(long, action = clap::ArgAction::SetTrue)]
+        extra_more: bool,
+
+        #[command(flatten)]
+        verbose: MultiVerbosity<CompilerLevel>,
+    }
+
+    fn command() -> clap::Command {
+        ["parser", "codegen"]
+            .iter()
+            .fold(Cli::command(), |cmd, name| {
+                MultiVerbosity::<CompilerLevel>::register(cmd, name)
+            })
+    }
+
+    fn parse(args: &[&str]) -> Result<Cli, clap::Error> {
+        let matches =
+            command().try_get_matches_from(std::iter::once("cli").chain(args.iter().copied()))?;
+        Cli::from_arg_matches(&matches)
+    }
+
+    #[test]
+    fn verify_app() {
+        Cli::command().debug_assert();
+        command().debug_assert();
+    }
+
+    #[test]
+    fn register() {
+        let cmd = command();
+        let longs = cmd
+            .get_arguments()
+            .filter_map(|arg| arg.get_long())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            longs,
+            [
+                "extra-more",
+                "parser-more",
+                "parser-quiet",
+                "codegen-more",
+                "codegen-quiet"
+            ]
+        );
+        assert!(cmd.get_arguments().all(|arg| arg.get_short().is_none()));
+    }
+
+    #[test]
+    fn independent_levels() {
+        let cli = parse(&["--parser-more", "--parser-more", "--codegen-quiet"]).unwrap();
+        assert_eq!(
+            cli.verbose.names().collect::<Vec<_>>(),
+            ["parser", "codegen"]
+        );
+        assert_eq!(cli.verbose.get("parser").unwrap().level_name(), "trace");
+        assert_eq!(cli.verbose.get("codegen").unwrap().level_name(), "warn");
+        assert!(cli.verbose.get("extra").is_none());
+
+        let cli = parse(&[]).unwrap();
+        assert_eq!(cli.verbose.get("parser").unwrap().verbose_count(), 0);
+        assert_eq!(cli.verbose.get_or_default("linker").level_name(), "info");
+        assert_eq!(cli.verbose.get_or_default("codegen").level_name(), "info");
+    }
+
+    #[test]
+    fn help() {
+        let cmd = command();
+        let help = |id: &str| {
+            cmd.get_arguments()
+                .find(|arg| arg.get_id() == id)
+                .and_then(|arg| arg.get_help())
+                .unwrap()
+                .to_string()
+        };
+        assert_eq!(
+            help("parser-more"),
+            "More output per occurrence, for parser"
+        );
+        assert_eq!(help("codegen-quiet"), "Less output, for codegen");
+        assert!(parse(&["-v"]).is_err());
+    }
+
+    #[test]
+    fn unregistered() {
+        let cli = Cli::parse_from(["cli"]);
+        assert_eq!(cli.verbose.names().count(), 0);
+        assert_eq!(cli.verbose.get_or_default("parser").level_name(), "info");
+    }
+
+    #[test]
+    fn conflicts() {
+        let err = parse(&["--parser-more", "--parser-quiet"]).unwrap_err();
+        assert_eq!(err.kind(), clap::error::ErrorKind::ArgumentConflict);
+        assert!(parse(&["--parser-more", "--codegen-quiet"]).is_ok());
+    }
+}
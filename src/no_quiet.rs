@@ -0,0 +1,114 @@
+use crate::ErrorLevel;
+use crate::LogLevel;
+use crate::Verbosity;
+
+/// [`Verbosity`] with only `--verbose`, for tools whose output can be added to but not suppressed
+///
+/// ```rust,no_run
+/// # use clap::Parser;
+/// use clap_verbosity_flag::VerbosityNoQuiet;
+///
+/// /// Le CLI
+/// #[derive(Debug, Parser)]
+/// struct Cli {
+///     #[command(flatten)]
+///     verbose: VerbosityNoQuiet,
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct VerbosityNoQuiet<L: LogLevel = ErrorLevel> {
+    inner: Verbosity<L>,
+}
+
+impl<L: LogLevel> VerbosityNoQuiet<L> {
+    /// Create a new verbosity instance by explicitly setting the values
+    pub fn new(verbose: u8) -> Self {
+        VerbosityNoQuiet {
+            inner: Verbosity::new(verbose, 0),
+        }
+    }
+
+    /// Unwrap the underlying [`Verbosity`]
+    pub fn into_inner(self) -> Verbosity<L> {
+        self.inner
+    }
+}
+
+impl<L: LogLevel> std::ops::Deref for VerbosityNoQuiet<L> {
+    type Target = Verbosity<L>;
+
+    fn deref(&self) -> &Verbosity<L> {
+        &self.inner
+    }
+}
+
+impl<L: LogLevel> From<VerbosityNoQuiet<L>> for Verbosity<L> {
+    fn from(verbosity: VerbosityNoQuiet<L>) -> Self {
+        verbosity.inner
+    }
+}
+
+impl<L: LogLevel> clap::FromArgMatches for VerbosityNoQuiet<L> {
+    fn from_arg_matches(matches: &clap::ArgMatches) -> Result<Self, clap::Error> {
+        let mut verbosity = Self::new(0);
+        verbosity.update_from_arg_matches(matches)?;
+        Ok(verbosity)
+    }
+
+    fn update_from_arg_matches(&mut self, matches: &clap::ArgMatches) -> Result<(), clap::Error> {
+        let verbosity = Verbosity::<L>::new(matches.get_count(crate::VERBOSE), 0).with_level_env();
+        // Quieting is impossible, even through the environment
+        self.inner = Verbosity::new(verbosity.verbose_count(), 0);
+        Ok(())
+    }
+}
+
+impl<L: LogLevel> clap::Args for VerbosityNoQuiet<L> {
+    fn augment_args(cmd: clap::Command) -> clap::Command {
+        cmd.arg(crate::verbose_arg::<L>())
+    }
+
+    fn augment_args_for_update(cmd: clap::Command) -> clap::Command {
+        Self::augment_args(cmd)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Debug, Parser)]
+    struct Cli {
+        #[command(flatten)]
+        verbose: VerbosityNoQuiet<crate::NoneLevel>,
+    }
+
+    #[test]
+    fn verify_app() {
+        use clap::CommandFactory;
+        Cli::command().debug_assert()
+    }
+
+    #[test]
+    fn verbose_only() {
+        let cli = Cli::parse_from(["cli", "-vv"]);
+        assert_eq!(cli.verbose.verbose_count(), 2);
+        assert!(!cli.verbose.is_silent());
+
+        // Without `--quiet`, only a silent default can be silent
+        let cli = Cli::parse_from(["cli"]);
+        assert!(cli.verbose.is_silent());
+
+        assert!(Cli::try_parse_from(["cli", "-q"]).is_err());
+        assert!(Cli::try_parse_from(["cli", "--quiet"]).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "log")]
+    fn log_level() {
+        let cli = Cli::parse_from(["cli", "-vv"]);
+        assert_eq!(cli.verbose.log_level(), Some(log::Level::Warn));
+        assert_eq!(cli.verbose.log_level_filter(), log::LevelFilter::Warn);
+    }
+}
@@ -0,0 +1,117 @@
+use crate::ErrorLevel;
+use crate::LogLevel;
+use crate::Verbosity;
+
+/// [`Verbosity`] with only `--quiet`, for tools whose output can be suppressed but not added to
+///
+/// This pairs well with a verbose default, like [`TraceLevel`][crate::TraceLevel].
+///
+/// ```rust,no_run
+/// # use clap::Parser;
+/// use clap_verbosity_flag::{TraceLevel, VerbosityNoVerbose};
+///
+/// /// Le CLI
+/// #[derive(Debug, Parser)]
+/// struct Cli {
+///     #[command(flatten)]
+///     verbose: VerbosityNoVerbose<TraceLevel>,
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct VerbosityNoVerbose<L: LogLevel = ErrorLevel> {
+    inner: Verbosity<L>,
+}
+
+impl<L: LogLevel> VerbosityNoVerbose<L> {
+    /// Create a new verbosity instance by explicitly setting the values
+    pub fn new(quiet: u8) -> Self {
+        VerbosityNoVerbose {
+            inner: Verbosity::new(0, quiet),
+        }
+    }
+
+    /// Unwrap the underlying [`Verbosity`]
+    pub fn into_inner(self) -> Verbosity<L> {
+        self.inner
+    }
+}
+
+impl<L: LogLevel> std::ops::Deref for VerbosityNoVerbose<L> {
+    type Target = Verbosity<L>;
+
+    fn deref(&self) -> &Verbosity<L> {
+        &self.inner
+    }
+}
+
+impl<L: LogLevel> From<VerbosityNoVerbose<L>> for Verbosity<L> {
+    fn from(verbosity: VerbosityNoVerbose<L>) -> Self {
+        verbosity.inner
+    }
+}
+
+impl<L: LogLevel> clap::FromArgMatches for VerbosityNoVerbose<L> {
+    fn from_arg_matches(matches: &clap::ArgMatches) -> Result<Self, clap::Error> {
+        let mut verbosity = Self::new(0);
+        verbosity.update_from_arg_matches(matches)?;
+        Ok(verbosity)
+    }
+
+    fn update_from_arg_matches(&mut self, matches: &clap::ArgMatches) -> Result<(), clap::Error> {
+        let verbosity = Verbosity::<L>::new(0, matches.get_count(crate::QUIET)).with_level_env();
+        // Adding output is impossible, even through the environment
+        self.inner = Verbosity::new(0, verbosity.quiet_count());
+        Ok(())
+    }
+}
+
+impl<L: LogLevel> clap::Args for VerbosityNoVerbose<L> {
+    fn augment_args(cmd: clap::Command) -> clap::Command {
+        cmd.arg(crate::quiet_arg::<L>().conflicts_with(clap::builder::Resettable::Reset))
+    }
+
+    fn augment_args_for_update(cmd: clap::Command) -> clap::Command {
+        Self::augment_args(cmd)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Debug, Parser)]
+    struct Cli {
+        #[command(flatten)]
+        verbose: VerbosityNoVerbose<crate::TraceLevel>,
+    }
+
+    #[test]
+    fn verify_app() {
+        use clap::CommandFactory;
+        Cli::command().debug_assert()
+    }
+
+    #[test]
+    fn quiet_only() {
+        let cli = Cli::parse_from(["cli", "-qq"]);
+        assert_eq!(cli.verbose.quiet_count(), 2);
+        assert_eq!(cli.verbose.as_i8(), 2);
+
+        let cli = Cli::parse_from(["cli", "-qqqqq"]);
+        assert!(cli.verbose.is_silent());
+
+        assert!(Cli::try_parse_from(["cli", "-v"]).is_err());
+        assert!(Cli::try_parse_from(["cli", "--verbose"]).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "log")]
+    fn log_level() {
+        let cli = Cli::parse_from(["cli"]);
+        assert_eq!(cli.verbose.log_level(), Some(log::Level::Trace));
+
+        let cli = Cli::parse_from(["cli", "-q"]);
+        assert_eq!(cli.verbose.log_level(), Some(log::Level::Debug));
+    }
+}
@@ -0,0 +1,104 @@
+use opentelemetry::logs::LogRecord;
+use opentelemetry::logs::Severity;
+
+use crate::LogLevel;
+use crate::Verbosity;
+
+impl<L: LogLevel> Verbosity<L> {
+    /// Get the OpenTelemetry severity
+    ///
+    /// `None`, or unspecified, means all output is disabled.  Like [`Verbosity::level_name`], this
+    /// is bounded by [`LogLevel::min_level`] and [`LogLevel::max_level`].
+    ///
+    /// | Verbosity | Severity                |
+    /// |-----------|-------------------------|
+    /// | `-1`      | `None`                  |
+    /// | `0`       | `Some(Severity::Error)` |
+    /// | `1`       | `Some(Severity::Warn)`  |
+    /// | `2`       | `Some(Severity::Info)`  |
+    /// | `3`       | `Some(Severity::Debug)` |
+    /// | `4`       | `Some(Severity::Trace)` |
+    pub fn otel_severity(&self) -> Option<Severity> {
+        match self.level_verbosity() {
+            i8::MIN..=-1 => None,
+            0 => Some(Severity::Error),
+            1 => Some(Severity::Warn),
+            2 => Some(Severity::Info),
+            3 => Some(Severity::Debug),
+            4.. => Some(Severity::Trace),
+        }
+    }
+
+    /// Get the OpenTelemetry severity text, like `INFO`, or `UNSPECIFIED` when all output is
+    /// disabled
+    pub fn otel_severity_text(&self) -> &'static str {
+        self.otel_severity()
+            .map(|severity| severity.name())
+            .unwrap_or("UNSPECIFIED")
+    }
+
+    /// Create a [`LogRecord`] with [`Verbosity::otel_severity`] and
+    /// [`Verbosity::otel_severity_text`] filled in
+    pub fn to_otel_log_record(&self) -> LogRecord {
+        let builder = LogRecord::builder().with_severity_text(self.otel_severity_text());
+        match self.otel_severity() {
+            Some(severity) => builder.with_severity_number(severity),
+            None => builder,
+        }
+        .build()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ErrorLevel;
+
+    #[test]
+    fn otel_severity() {
+        let expected = [
+            (None, "UNSPECIFIED"),
+            (Some(Severity::Error), "ERROR"),
+            (Some(Severity::Warn), "WARN"),
+            (Some(Severity::Info), "INFO"),
+            (Some(Severity::Debug), "DEBUG"),
+            (Some(Severity::Trace), "TRACE"),
+        ];
+        for ((severity, text), net) in expected.into_iter().zip(-1..) {
+            let verbosity = Verbosity::<ErrorLevel>::from_count(net);
+            assert_eq!(verbosity.otel_severity(), severity);
+            assert_eq!(verbosity.otel_severity_text(), text);
+
+            let record = verbosity.to_otel_log_record();
+            assert_eq!(record.severity_number, severity);
+            assert_eq!(record.severity_text.as_deref(), Some(text));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "log")]
+    fn otel_severity_capped() {
+        #[derive(Copy, Clone, Debug, Default)]
+        struct CappedLevel;
+
+        impl LogLevel for CappedLevel {
+            fn default_log() -> Option<log::Level> {
+                Some(log::Level::Info)
+            }
+
+            #[cfg(feature = "tracing")]
+            fn default_tracing() -> Option<tracing_subscriber::filter::LevelFilter> {
+                Some(tracing_subscriber::filter::LevelFilter::INFO)
+            }
+
+            fn max_level() -> Option<log::Level> {
+                Some(log::Level::Debug)
+            }
+        }
+
+        let verbosity = Verbosity::<CappedLevel>::new(3, 0);
+        assert_eq!(verbosity.log_level(), Some(log::Level::Debug));
+        assert_eq!(verbosity.otel_severity(), Some(Severity::Debug));
+        assert_eq!(verbosity.otel_severity_text(), "DEBUG");
+    }
+}
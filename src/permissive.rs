@@ -0,0 +1,119 @@
+use crate::ErrorLevel;
+use crate::LogLevel;
+use crate::Verbosity;
+
+/// [`Verbosity`] that allows `--verbose` and `--quiet` to be used together
+///
+/// The counts cancel each other out, so `-vv -q` is the same as `-v`.  This is useful when a
+/// global `--verbose` should be able to be overridden by `--quiet` on a subcommand, or vice versa.
+///
+/// ```rust,no_run
+/// # use clap::Parser;
+/// use clap_verbosity_flag::VerbosityPermissive;
+///
+/// /// Le CLI
+/// #[derive(Debug, Parser)]
+/// struct Cli {
+///     #[command(flatten)]
+///     verbose: VerbosityPermissive,
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct VerbosityPermissive<L: LogLevel = ErrorLevel> {
+    inner: Verbosity<L>,
+}
+
+impl<L: LogLevel> VerbosityPermissive<L> {
+    /// Create a new verbosity instance by explicitly setting the values
+    pub fn new(verbose: u8, quiet: u8) -> Self {
+        VerbosityPermissive {
+            inner: Verbosity::new(verbose, quiet),
+        }
+    }
+
+    /// Unwrap the underlying [`Verbosity`]
+    pub fn into_inner(self) -> Verbosity<L> {
+        self.inner
+    }
+}
+
+impl<L: LogLevel> std::ops::Deref for VerbosityPermissive<L> {
+    type Target = Verbosity<L>;
+
+    fn deref(&self) -> &Verbosity<L> {
+        &self.inner
+    }
+}
+
+impl<L: LogLevel> From<VerbosityPermissive<L>> for Verbosity<L> {
+    fn from(verbosity: VerbosityPermissive<L>) -> Self {
+        verbosity.inner
+    }
+}
+
+impl<L: LogLevel> clap::FromArgMatches for VerbosityPermissive<L> {
+    fn from_arg_matches(matches: &clap::ArgMatches) -> Result<Self, clap::Error> {
+        let mut verbosity = Self::new(0, 0);
+        verbosity.update_from_arg_matches(matches)?;
+        Ok(verbosity)
+    }
+
+    fn update_from_arg_matches(&mut self, matches: &clap::ArgMatches) -> Result<(), clap::Error> {
+        self.inner = Verbosity::new(
+            matches.get_count(crate::VERBOSE),
+            matches.get_count(crate::QUIET),
+        )
+        .with_level_env();
+        Ok(())
+    }
+}
+
+impl<L: LogLevel> clap::Args for VerbosityPermissive<L> {
+    fn augment_args(cmd: clap::Command) -> clap::Command {
+        cmd.arg(crate::verbose_arg::<L>())
+            .arg(crate::quiet_arg::<L>().conflicts_with(clap::builder::Resettable::Reset))
+    }
+
+    fn augment_args_for_update(cmd: clap::Command) -> clap::Command {
+        Self::augment_args(cmd)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Debug, Parser)]
+    struct Cli {
+        #[command(flatten)]
+        verbose: VerbosityPermissive,
+
+        #[command(subcommand)]
+        command: Option<Command>,
+    }
+
+    #[derive(Debug, clap::Subcommand)]
+    enum Command {
+        Run,
+    }
+
+    #[test]
+    fn verify_app() {
+        use clap::CommandFactory;
+        Cli::command().debug_assert()
+    }
+
+    #[test]
+    fn verbose_and_quiet_together() {
+        let cli = Cli::parse_from(["cli", "-vv", "-q"]);
+        assert_eq!(cli.verbose.verbose_count(), 2);
+        assert_eq!(cli.verbose.quiet_count(), 1);
+        assert_eq!(cli.verbose.as_i8(), 1);
+
+        let cli = Cli::parse_from(["cli", "-v", "run", "-qqq"]);
+        assert!(matches!(cli.command, Some(Command::Run)));
+        assert_eq!(cli.verbose.as_i8(), -1);
+        assert!(cli.verbose.is_silent());
+    }
+}
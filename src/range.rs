@@ -0,0 +1,234 @@
+use std::fmt;
+
+use crate::LogLevel;
+use crate::Verbosity;
+
+/// [`Verbosity`] with its level restricted to `min..=max`
+///
+/// Unlike [`LogLevel::min_level`] and [`LogLevel::max_level`], the bounds are picked at runtime,
+/// like from a config file.  The level accessors below are restricted, while the rest of
+/// [`Verbosity`]'s methods, available through `Deref`, see the underlying level.  For those, use
+/// [`VerbosityRange::clamped`].
+///
+/// The level is resolved, and restricted, on each call, so it stays within bounds even if the
+/// default changes later, like for [`DynamicLevel`][crate::DynamicLevel].
+///
+/// ```rust
+/// use clap_verbosity_flag::{InfoLevel, Verbosity, VerbosityRange};
+///
+/// let verbosity = Verbosity::<InfoLevel>::new(1, 0);
+/// let range = VerbosityRange::new(verbosity, Some(log::Level::Warn), Some(log::Level::Debug));
+/// assert_eq!(range.log_level(), Some(log::Level::Debug));
+/// assert_eq!(range.level_name(), "debug");
+/// ```
+#[derive(Debug, Clone)]
+pub struct VerbosityRange<L: LogLevel> {
+    inner: Verbosity<L>,
+    min: Option<log::Level>,
+    max: Option<log::Level>,
+}
+
+impl<L: LogLevel> VerbosityRange<L> {
+    /// Restrict `inner` to `min..=max`, where `None` is the quietest level
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, if `min` is more verbose than `max`, or if `inner` is already outside of
+    /// `min..=max`.  Release builds clamp `inner` instead.
+    pub fn new(inner: Verbosity<L>, min: Option<log::Level>, max: Option<log::Level>) -> Self {
+        let min_value = crate::level_value_log(min);
+        let max_value = crate::level_value_log(max);
+        debug_assert!(
+            min_value <= max_value,
+            "minimum level ({min:?}) must not be more verbose than the maximum level ({max:?})"
+        );
+        debug_assert!(
+            (min_value..=max_value).contains(&crate::level_value_log(inner.log_level())),
+            "level ({:?}) must be within {min:?}..={max:?}",
+            inner.log_level()
+        );
+        VerbosityRange { inner, min, max }
+    }
+
+    /// Get the [`Verbosity`] with its counts adjusted to be within `min..=max`
+    pub fn clamped(&self) -> Verbosity<L> {
+        self.inner.clamped(self.min, self.max)
+    }
+
+    /// Get the log level, restricted to `min..=max`
+    pub fn log_level(&self) -> Option<log::Level> {
+        self.clamped().log_level()
+    }
+
+    /// Get the log level filter, restricted to `min..=max`
+    pub fn log_level_filter(&self) -> log::LevelFilter {
+        self.clamped().log_level_filter()
+    }
+
+    #[cfg(feature = "tracing")]
+    /// Get the tracing level filter, restricted to `min..=max`
+    pub fn tracing_level_filter(&self) -> tracing_subscriber::filter::LevelFilter {
+        self.clamped().tracing_level_filter()
+    }
+
+    /// If all output is disabled, which requires `min` to be `None`
+    pub fn is_silent(&self) -> bool {
+        self.clamped().is_silent()
+    }
+
+    /// Get the name of the level, restricted to `min..=max`
+    pub fn level_name(&self) -> &'static str {
+        self.clamped().level_name()
+    }
+
+    /// Unwrap the underlying, unrestricted [`Verbosity`]
+    pub fn into_inner(self) -> Verbosity<L> {
+        self.inner
+    }
+}
+
+impl<L: LogLevel> std::ops::Deref for VerbosityRange<L> {
+    type Target = Verbosity<L>;
+
+    fn deref(&self) -> &Verbosity<L> {
+        &self.inner
+    }
+}
+
+/// Shows the restricted level, like `info` or `off`
+impl<L: LogLevel> fmt::Display for VerbosityRange<L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.level_name())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::DynamicLevel;
+    use crate::InfoLevel;
+
+    fn range(verbose: u8, quiet: u8) -> VerbosityRange<InfoLevel> {
+        VerbosityRange::new(
+            Verbosity::new(verbose, quiet),
+            Some(log::Level::Warn),
+            Some(log::Level::Debug),
+        )
+    }
+
+    #[test]
+    fn in_range() {
+        let quiet = range(0, 1);
+        assert_eq!(quiet.log_level(), Some(log::Level::Warn));
+        assert_eq!(quiet.log_level_filter(), log::LevelFilter::Warn);
+        assert_eq!(quiet.level_name(), "warn");
+        assert!(!quiet.is_silent());
+
+        let loud = range(1, 0);
+        assert_eq!(loud.log_level(), Some(log::Level::Debug));
+        assert_eq!(loud.to_string(), "debug");
+        #[cfg(feature = "tracing")]
+        assert_eq!(
+            loud.tracing_level_filter(),
+            tracing_subscriber::filter::LevelFilter::DEBUG
+        );
+    }
+
+    #[test]
+    fn silent() {
+        let range = VerbosityRange::new(Verbosity::<InfoLevel>::new(0, 3), None, None);
+        assert!(range.is_silent());
+        assert_eq!(range.level_name(), "off");
+    }
+
+    #[test]
+    #[cfg(not(debug_assertions))]
+    fn clamps() {
+        let quiet = range(0, 3);
+        assert_eq!(quiet.log_level(), Some(log::Level::Warn));
+        assert_eq!(quiet.log_level_filter(), log::LevelFilter::Warn);
+        assert_eq!(quiet.level_name(), "warn");
+        assert!(!quiet.is_silent());
+
+        let loud = range(4, 0);
+        assert_eq!(loud.log_level(), Some(log::Level::Debug));
+        assert_eq!(loud.log_level_filter(), log::LevelFilter::Debug);
+        assert_eq!(loud.to_string(), "debug");
+        #[cfg(feature = "tracing")]
+        assert_eq!(
+            loud.tracing_level_filter(),
+            tracing_subscriber::filter::LevelFilter::DEBUG
+        );
+        assert_eq!(loud.clamped().verbose_count(), 1);
+        assert_eq!(loud.into_inner().log_level(), Some(log::Level::Trace));
+    }
+
+    #[test]
+    fn inner_is_unrestricted() {
+        let range = range(1, 0);
+        assert_eq!(range.clamped().verbose_count(), 1);
+        assert_eq!(range.verbose_count(), 1);
+        assert!(!range.is_at_max());
+        assert_eq!(range.into_inner().verbose_count(), 1);
+    }
+
+    fn dynamic_range(verbose: u8, quiet: u8) -> VerbosityRange<DynamicLevel> {
+        Verbosity::with_runtime_default(Some(log::Level::Info));
+        VerbosityRange::new(
+            Verbosity::new(verbose, quiet),
+            Some(log::Level::Warn),
+            Some(log::Level::Debug),
+        )
+    }
+
+    #[test]
+    fn clamps_max() {
+        let range = dynamic_range(1, 0);
+        assert_eq!(range.log_level(), Some(log::Level::Debug));
+
+        Verbosity::with_runtime_default(Some(log::Level::Trace));
+        assert_eq!(range.log_level(), Some(log::Level::Debug));
+        assert_eq!(range.log_level_filter(), log::LevelFilter::Debug);
+        assert_eq!(range.to_string(), "debug");
+        assert_eq!(range.clamped().log_level(), Some(log::Level::Debug));
+        assert_eq!(range.into_inner().log_level(), Some(log::Level::Trace));
+    }
+
+    #[test]
+    fn clamps_min() {
+        let range = dynamic_range(0, 1);
+        assert_eq!(range.log_level(), Some(log::Level::Warn));
+
+        Verbosity::with_runtime_default(Some(log::Level::Error));
+        assert_eq!(range.log_level(), Some(log::Level::Warn));
+        assert_eq!(range.log_level_filter(), log::LevelFilter::Warn);
+        assert_eq!(range.level_name(), "warn");
+        assert!(!range.is_silent());
+        assert!(range.inner.is_silent());
+    }
+
+    #[test]
+    #[should_panic = "must not be more verbose"]
+    #[cfg(debug_assertions)]
+    fn inverted_bounds() {
+        VerbosityRange::new(
+            Verbosity::<InfoLevel>::new(0, 0),
+            Some(log::Level::Debug),
+            Some(log::Level::Warn),
+        );
+    }
+
+    #[test]
+    #[should_panic = "must be within"]
+    #[cfg(debug_assertions)]
+    fn too_verbose() {
+        range(4, 0);
+    }
+
+    #[test]
+    #[should_panic = "must be within"]
+    #[cfg(debug_assertions)]
+    fn too_quiet() {
+        range(0, 3);
+    }
+}
@@ -0,0 +1,32 @@
+use crate::LogLevel;
+use crate::Verbosity;
+
+impl<L: LogLevel> Verbosity<L> {
+    /// Install a global [`simplelog::SimpleLogger`] at [`Verbosity::log_level_filter`]
+    pub fn init_simplelog(&self) -> Result<(), log::SetLoggerError> {
+        simplelog::SimpleLogger::init(self.log_level_filter(), simplelog::Config::default())
+    }
+
+    /// Install a global, colored [`simplelog::TermLogger`] at [`Verbosity::log_level_filter`]
+    pub fn init_termlog(&self, mode: simplelog::TerminalMode) -> Result<(), log::SetLoggerError> {
+        simplelog::TermLogger::init(
+            self.log_level_filter(),
+            simplelog::Config::default(),
+            mode,
+            simplelog::ColorChoice::Auto,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ErrorLevel;
+
+    #[test]
+    fn level_filter_is_log_level_filter() {
+        let verbosity = Verbosity::<ErrorLevel>::new(2, 0);
+        let filter: simplelog::LevelFilter = verbosity.log_level_filter();
+        assert_eq!(filter, simplelog::LevelFilter::Info);
+    }
+}
@@ -0,0 +1,162 @@
+use crate::LogLevel;
+use crate::Verbosity;
+
+impl<L: LogLevel> Verbosity<L> {
+    /// Get the `slog` filter level.
+    ///
+    /// Errors include [`slog::Level::Critical`].  Like [`Verbosity::level_name`], this is bounded by
+    /// [`LogLevel::min_level`] and [`LogLevel::max_level`].
+    pub fn slog_level(&self) -> slog::FilterLevel {
+        match self.level_verbosity() {
+            i8::MIN..=-1 => slog::FilterLevel::Off,
+            0 => slog::FilterLevel::Error,
+            1 => slog::FilterLevel::Warning,
+            2 => slog::FilterLevel::Info,
+            3 => slog::FilterLevel::Debug,
+            4.. => slog::FilterLevel::Trace,
+        }
+    }
+
+    /// Wrap `logger` so it only passes on records enabled by [`Verbosity::slog_level`]
+    ///
+    /// ```rust,no_run
+    /// # use clap::Parser;
+    /// # use clap_verbosity_flag::Verbosity;
+    /// #
+    /// # /// Le CLI
+    /// # #[derive(Debug, Parser)]
+    /// # struct Cli {
+    /// #     #[command(flatten)]
+    /// #     verbose: Verbosity,
+    /// # }
+    /// # fn drain() -> slog::Discard { slog::Discard }
+    /// let cli = Cli::parse();
+    /// let root = slog::Logger::root(drain(), slog::o!());
+    /// let logger = cli.verbose.apply_to_slog_logger(&root);
+    /// slog::warn!(logger, "Engines smoking");
+    /// ```
+    pub fn apply_to_slog_logger(&self, logger: &slog::Logger) -> slog::Logger {
+        use slog::Drain as _;
+
+        let level = self.slog_level();
+        let drain = slog::Filter::new(logger.clone(), move |record: &slog::Record<'_>| {
+            level.accepts(record.level())
+        });
+        slog::Logger::root(drain.ignore_res(), slog::o!())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ErrorLevel;
+    use crate::InfoLevel;
+
+    #[test]
+    fn slog_level() {
+        for (quiet, expected) in [
+            (3, slog::FilterLevel::Off),
+            (2, slog::FilterLevel::Error),
+            (1, slog::FilterLevel::Warning),
+            (0, slog::FilterLevel::Info),
+        ] {
+            assert_eq!(Verbosity::<InfoLevel>::new(0, quiet).slog_level(), expected);
+        }
+        assert_eq!(
+            Verbosity::<InfoLevel>::new(1, 0).slog_level(),
+            slog::FilterLevel::Debug
+        );
+        assert_eq!(
+            Verbosity::<InfoLevel>::new(5, 0).slog_level(),
+            slog::FilterLevel::Trace
+        );
+    }
+
+    #[derive(Copy, Clone, Debug, Default)]
+    struct CappedLevel;
+
+    impl LogLevel for CappedLevel {
+        #[cfg(feature = "log")]
+        fn default_log() -> Option<log::Level> {
+            Some(log::Level::Info)
+        }
+
+        #[cfg(feature = "tracing")]
+        fn default_tracing() -> Option<tracing_subscriber::filter::LevelFilter> {
+            Some(tracing_subscriber::filter::LevelFilter::INFO)
+        }
+
+        #[cfg(feature = "log")]
+        fn max_level() -> Option<log::Level> {
+            Some(log::Level::Debug)
+        }
+
+        #[cfg(feature = "tracing")]
+        fn max_tracing_level() -> tracing_subscriber::filter::LevelFilter {
+            tracing_subscriber::filter::LevelFilter::DEBUG
+        }
+
+        #[cfg(feature = "log")]
+        fn min_level() -> Option<log::Level> {
+            Some(log::Level::Warn)
+        }
+
+        #[cfg(feature = "tracing")]
+        fn min_tracing_level() -> tracing_subscriber::filter::LevelFilter {
+            tracing_subscriber::filter::LevelFilter::WARN
+        }
+    }
+
+    #[test]
+    fn slog_level_capped() {
+        let loud = Verbosity::<CappedLevel>::new(5, 0);
+        assert_eq!(loud.slog_level(), slog::FilterLevel::Debug);
+        #[cfg(feature = "log")]
+        assert_eq!(loud.log_level(), Some(log::Level::Debug));
+
+        let quiet = Verbosity::<CappedLevel>::new(0, 5);
+        assert_eq!(quiet.slog_level(), slog::FilterLevel::Warning);
+        #[cfg(feature = "log")]
+        assert_eq!(quiet.log_level(), Some(log::Level::Warn));
+    }
+
+    #[test]
+    fn apply_to_slog_logger() {
+        use std::sync::{Arc, Mutex};
+
+        #[derive(Clone, Default)]
+        struct Collect(Arc<Mutex<Vec<slog::Level>>>);
+
+        impl slog::Drain for Collect {
+            type Ok = ();
+            type Err = slog::Never;
+
+            fn log(
+                &self,
+                record: &slog::Record<'_>,
+                _: &slog::OwnedKVList,
+            ) -> Result<(), slog::Never> {
+                self.0.lock().unwrap().push(record.level());
+                Ok(())
+            }
+        }
+
+        let collect = Collect::default();
+        let root = slog::Logger::root(collect.clone(), slog::o!());
+        let logger = Verbosity::<ErrorLevel>::new(1, 0).apply_to_slog_logger(&root);
+        slog::crit!(logger, "crit");
+        slog::error!(logger, "error");
+        slog::warn!(logger, "warn");
+        slog::info!(logger, "info");
+        slog::debug!(logger, "debug");
+
+        assert_eq!(
+            *collect.0.lock().unwrap(),
+            [
+                slog::Level::Critical,
+                slog::Level::Error,
+                slog::Level::Warning
+            ]
+        );
+    }
+}
@@ -0,0 +1,41 @@
+use crate::LogLevel;
+use crate::Verbosity;
+
+impl<L: LogLevel> Verbosity<L> {
+    /// Install a global `tracing_subscriber::fmt` subscriber at
+    /// [`Verbosity::tracing_level_filter`]
+    ///
+    /// ```rust,no_run
+    /// # use clap::Parser;
+    /// # use clap_verbosity_flag::Verbosity;
+    /// #
+    /// # /// Le CLI
+    /// # #[derive(Debug, Parser)]
+    /// # struct Cli {
+    /// #     #[command(flatten)]
+    /// #     verbose: Verbosity,
+    /// # }
+    /// let cli = Cli::parse();
+    /// cli.verbose.init_tracing_subscriber();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If a global subscriber was already installed, see
+    /// [`Verbosity::try_init_tracing_subscriber`]
+    pub fn init_tracing_subscriber(&self) {
+        tracing_subscriber::fmt()
+            .with_max_level(self.tracing_level_filter())
+            .init();
+    }
+
+    /// Like [`Verbosity::init_tracing_subscriber`], failing if a global subscriber was already
+    /// installed
+    pub fn try_init_tracing_subscriber(
+        &self,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        tracing_subscriber::fmt()
+            .with_max_level(self.tracing_level_filter())
+            .try_init()
+    }
+}
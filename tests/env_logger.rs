@@ -0,0 +1,11 @@
+#![cfg(feature = "env-logger")]
+
+use clap_verbosity_flag::{InfoLevel, Verbosity};
+
+#[test]
+fn init_env_logger() {
+    let verbosity = Verbosity::<InfoLevel>::new(1, 0);
+    verbosity.init_env_logger();
+    assert_eq!(log::max_level(), log::LevelFilter::Debug);
+    assert!(verbosity.try_init_env_logger().is_err());
+}
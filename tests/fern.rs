@@ -0,0 +1,11 @@
+#![cfg(feature = "fern")]
+
+use clap_verbosity_flag::{InfoLevel, Verbosity};
+
+#[test]
+fn init_fern_logger() {
+    let verbosity = Verbosity::<InfoLevel>::new(0, 1);
+    verbosity.init_fern_logger().unwrap();
+    assert_eq!(log::max_level(), log::LevelFilter::Warn);
+    assert!(verbosity.init_fern_logger().is_err());
+}
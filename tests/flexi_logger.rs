@@ -0,0 +1,14 @@
+#![cfg(feature = "flexi-logger")]
+
+use clap_verbosity_flag::{InfoLevel, Verbosity};
+
+#[test]
+fn init_flexi_logger() {
+    let verbosity = Verbosity::<InfoLevel>::new(0, 1);
+    let handle = verbosity.init_flexi_logger().unwrap();
+    assert!(log::log_enabled!(log::Level::Warn));
+    assert!(!log::log_enabled!(log::Level::Info));
+
+    handle.parse_new_spec("debug").unwrap();
+    assert!(log::log_enabled!(log::Level::Debug));
+}
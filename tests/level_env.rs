@@ -0,0 +1,146 @@
+use clap::Parser;
+use clap_verbosity_flag::{
+    LocalVerbosity, LogLevel, LogLevelArg, MultiVerbosity, Verbosity, VerbosityCount,
+    VerbosityNoQuiet, VerbosityNoVerbose, VerbosityPermissive,
+};
+
+#[derive(Copy, Clone, Debug, Default)]
+struct NamedLevel;
+
+impl LogLevel for NamedLevel {
+    #[cfg(feature = "log")]
+    fn default_log() -> Option<log::Level> {
+        Some(log::Level::Error)
+    }
+
+    #[cfg(feature = "tracing")]
+    fn default_tracing() -> Option<tracing_subscriber::filter::LevelFilter> {
+        Some(tracing_subscriber::filter::LevelFilter::ERROR)
+    }
+
+    fn verbose_env_var_name() -> Option<&'static str> {
+        Some("NAMED_VERBOSE")
+    }
+
+    fn quiet_env_var_name() -> Option<&'static str> {
+        Some("NAMED_QUIET")
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default)]
+struct PrefixedLevel;
+
+impl LogLevel for PrefixedLevel {
+    #[cfg(feature = "log")]
+    fn default_log() -> Option<log::Level> {
+        Some(log::Level::Error)
+    }
+
+    #[cfg(feature = "tracing")]
+    fn default_tracing() -> Option<tracing_subscriber::filter::LevelFilter> {
+        Some(tracing_subscriber::filter::LevelFilter::ERROR)
+    }
+
+    fn env_var_prefix() -> Option<&'static str> {
+        Some("PREFIXED")
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default)]
+struct WrappedLevel;
+
+impl LogLevel for WrappedLevel {
+    #[cfg(feature = "log")]
+    fn default_log() -> Option<log::Level> {
+        Some(log::Level::Warn)
+    }
+
+    #[cfg(feature = "tracing")]
+    fn default_tracing() -> Option<tracing_subscriber::filter::LevelFilter> {
+        Some(tracing_subscriber::filter::LevelFilter::WARN)
+    }
+
+    fn env_var_prefix() -> Option<&'static str> {
+        Some("WRAPPED")
+    }
+}
+
+#[derive(Debug, Parser)]
+struct Cli<L: LogLevel + Clone + Send + Sync + 'static> {
+    #[command(flatten)]
+    verbose: Verbosity<L>,
+}
+
+#[test]
+fn named_env_vars() {
+    std::env::set_var("NAMED_VERBOSE", "2");
+    std::env::set_var("NAMED_QUIET", "not a count");
+
+    let cli = Cli::<NamedLevel>::parse_from(["cli"]);
+    assert_eq!(cli.verbose.verbose_count(), 2);
+    assert_eq!(cli.verbose.quiet_count(), 0);
+
+    // Flags are added on top
+    let cli = Cli::<NamedLevel>::parse_from(["cli", "-v"]);
+    assert_eq!(cli.verbose.verbose_count(), 3);
+
+    // Constructing directly doesn't read the environment
+    assert_eq!(Verbosity::<NamedLevel>::new(0, 0).verbose_count(), 0);
+}
+
+#[test]
+fn prefixed_env_vars() {
+    std::env::set_var("PREFIXED_QUIET", "1");
+
+    let cli = Cli::<PrefixedLevel>::parse_from(["cli"]);
+    assert_eq!(cli.verbose.quiet_count(), 1);
+    assert!(cli.verbose.is_silent());
+
+    let cli = Cli::<PrefixedLevel>::parse_from(["cli", "-vv"]);
+    assert_eq!(cli.verbose.verbose_count(), 2);
+    assert_eq!(cli.verbose.level_name(), "warn");
+}
+
+fn parse<T: clap::Args + clap::FromArgMatches>(args: &[&str]) -> T {
+    let cmd = T::augment_args(clap::Command::new("cli"));
+    let matches = cmd.get_matches_from(std::iter::once("cli").chain(args.iter().copied()));
+    T::from_arg_matches(&matches).unwrap()
+}
+
+#[test]
+fn wrapper_env_vars() {
+    std::env::set_var("WRAPPED_VERBOSE", "2");
+    std::env::set_var("WRAPPED_QUIET", "1");
+
+    let counts =
+        |verbosity: &Verbosity<WrappedLevel>| (verbosity.verbose_count(), verbosity.quiet_count());
+    assert_eq!(counts(&parse::<Verbosity<WrappedLevel>>(&[])), (2, 1));
+    assert_eq!(counts(&parse::<VerbosityCount<WrappedLevel>>(&[])), (2, 1));
+    assert_eq!(
+        counts(&parse::<VerbosityPermissive<WrappedLevel>>(&[])),
+        (2, 1)
+    );
+    assert_eq!(counts(&parse::<LocalVerbosity<WrappedLevel>>(&[])), (2, 1));
+    assert_eq!(counts(&parse::<LogLevelArg<WrappedLevel>>(&[])), (2, 1));
+    assert_eq!(counts(&parse::<LogLevelArg<WrappedLevel>>(&["-v"])), (3, 1));
+
+    // The wrappers' restrictions still apply
+    assert_eq!(
+        counts(&parse::<VerbosityNoQuiet<WrappedLevel>>(&[])),
+        (2, 0)
+    );
+    assert_eq!(
+        counts(&parse::<VerbosityNoVerbose<WrappedLevel>>(&[])),
+        (0, 1)
+    );
+
+    let cmd = MultiVerbosity::<WrappedLevel>::register(clap::Command::new("cli"), "parser");
+    let matches = cmd.get_matches_from(["cli", "--parser-verbose"]);
+    let multi =
+        <MultiVerbosity<WrappedLevel> as clap::FromArgMatches>::from_arg_matches(&matches).unwrap();
+    assert_eq!(counts(multi.get("parser").unwrap()), (3, 1));
+
+    // An explicit level isn't adjusted
+    let arg = parse::<LogLevelArg<WrappedLevel>>(&["--log-level", "error"]);
+    assert_eq!(arg.level_name(), "error");
+}
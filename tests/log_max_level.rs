@@ -0,0 +1,14 @@
+#![cfg(feature = "log")]
+
+use clap_verbosity_flag::{InfoLevel, Verbosity};
+
+#[test]
+fn apply_log_max_level() {
+    Verbosity::<InfoLevel>::new(1, 0).apply_log_max_level();
+    assert_eq!(log::max_level(), log::LevelFilter::Debug);
+
+    Verbosity::<InfoLevel>::silent()
+        .try_apply_log_max_level()
+        .unwrap();
+    assert_eq!(log::max_level(), log::LevelFilter::Off);
+}
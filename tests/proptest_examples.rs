@@ -0,0 +1,35 @@
+#![cfg(feature = "proptest")]
+
+use clap_verbosity_flag::{verbosity_strategy, ErrorLevel, InfoLevel, NoneLevel, Verbosity};
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn covers_every_level(verbosity in verbosity_strategy::<InfoLevel>()) {
+        prop_assert!((-1..=4).contains(&verbosity.as_i8()));
+    }
+
+    #[test]
+    #[cfg(feature = "log")]
+    fn silent_matches_log_filter(verbosity in verbosity_strategy::<ErrorLevel>()) {
+        prop_assert_eq!(
+            verbosity.is_silent(),
+            verbosity.log_level_filter() == log::LevelFilter::Off
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "tracing")]
+    fn silent_matches_tracing_filter(verbosity in verbosity_strategy::<NoneLevel>()) {
+        prop_assert_eq!(
+            verbosity.is_silent(),
+            verbosity.tracing_level_filter() == tracing_subscriber::filter::LevelFilter::OFF
+        );
+    }
+
+    #[test]
+    fn parse_display_round_trip(verbosity in verbosity_strategy::<NoneLevel>()) {
+        let parsed: Verbosity<NoneLevel> = verbosity.to_string().parse().unwrap();
+        prop_assert_eq!(parsed, verbosity);
+    }
+}
@@ -0,0 +1,22 @@
+#![cfg(feature = "log")]
+
+use clap_verbosity_flag::{InfoLevel, Verbosity};
+
+#[test]
+fn set_rust_log_env() {
+    for verbosity in [
+        Verbosity::<InfoLevel>::silent(),
+        Verbosity::new(0, 2),
+        Verbosity::new(0, 0),
+        Verbosity::new(2, 0),
+    ] {
+        verbosity.set_rust_log_env();
+        assert_eq!(
+            std::env::var("RUST_LOG").unwrap(),
+            verbosity.to_env_logger_filter_string()
+        );
+
+        let logger = env_logger::Builder::from_env("RUST_LOG").build();
+        assert_eq!(logger.filter(), verbosity.log_level_filter());
+    }
+}
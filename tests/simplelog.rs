@@ -0,0 +1,13 @@
+#![cfg(feature = "simplelog")]
+
+use clap_verbosity_flag::{InfoLevel, Verbosity};
+
+#[test]
+fn init_simplelog() {
+    let verbosity = Verbosity::<InfoLevel>::new(1, 0);
+    verbosity.init_simplelog().unwrap();
+    assert_eq!(log::max_level(), log::LevelFilter::Debug);
+    assert!(verbosity
+        .init_termlog(simplelog::TerminalMode::Stderr)
+        .is_err());
+}
@@ -0,0 +1,120 @@
+use std::ffi::OsStr;
+use std::process::Command;
+
+use clap_verbosity_flag::{InfoLevel, LogLevel, Verbosity};
+
+#[derive(Copy, Clone, Debug, Default)]
+struct AppLevel;
+
+impl LogLevel for AppLevel {
+    #[cfg(feature = "log")]
+    fn default_log() -> Option<log::Level> {
+        Some(log::Level::Warn)
+    }
+
+    #[cfg(feature = "tracing")]
+    fn default_tracing() -> Option<tracing_subscriber::filter::LevelFilter> {
+        Some(tracing_subscriber::filter::LevelFilter::WARN)
+    }
+
+    #[cfg(not(any(feature = "log", feature = "tracing")))]
+    fn default_known_level() -> clap_verbosity_flag::KnownLevel {
+        clap_verbosity_flag::KnownLevel::Warn
+    }
+
+    fn env_var() -> Option<&'static str> {
+        Some("MY_APP_LOG")
+    }
+}
+
+fn env<'c>(cmd: &'c Command, var: &str) -> Option<&'c OsStr> {
+    cmd.get_envs()
+        .find(|(key, _)| *key == var)
+        .and_then(|(_, value)| value)
+}
+
+#[test]
+fn propagate_to_subprocess() {
+    let mut cmd = Command::new("child");
+    Verbosity::<InfoLevel>::new(1, 0).propagate_to_subprocess(&mut cmd);
+    assert_eq!(env(&cmd, "RUST_LOG"), Some(OsStr::new("debug")));
+    assert_eq!(env(&cmd, "MY_APP_LOG"), None);
+
+    let mut cmd = Command::new("child");
+    Verbosity::<AppLevel>::new(0, 1).propagate_to_subprocess(&mut cmd);
+    assert_eq!(env(&cmd, "RUST_LOG"), Some(OsStr::new("error")));
+    assert_eq!(env(&cmd, "MY_APP_LOG"), Some(OsStr::new("error")));
+}
+
+#[test]
+#[cfg(unix)]
+fn subprocess_sees_level() {
+    let mut cmd = Command::new("sh");
+    cmd.args(["-c", "printf %s \"$MY_APP_LOG\""]);
+    Verbosity::<AppLevel>::silent().propagate_to_subprocess(&mut cmd);
+    let output = cmd.output().unwrap();
+    assert_eq!(output.stdout, b"off");
+}
+
+#[test]
+fn from_subprocess_env() {
+    for verbosity in [
+        Verbosity::<AppLevel>::silent(),
+        Verbosity::new(0, 0),
+        Verbosity::new(2, 0),
+    ] {
+        let mut cmd = Command::new("child");
+        verbosity.propagate_to_subprocess(&mut cmd);
+        std::env::set_var("CHILD_LOG", env(&cmd, "MY_APP_LOG").unwrap());
+        let round_trip = Verbosity::<AppLevel>::from_subprocess_env("CHILD_LOG");
+        assert_eq!(round_trip.level_name(), verbosity.level_name());
+    }
+}
+
+#[test]
+fn write_env_for_subprocess() {
+    let mut cmd = Command::new("child");
+    let verbosity = Verbosity::<InfoLevel>::new(0, 1);
+    verbosity.write_env_for_subprocess(&mut cmd, "OTHER_LOG");
+    verbosity.set_rust_log_for_subprocess(&mut cmd);
+    assert_eq!(env(&cmd, "OTHER_LOG"), Some(OsStr::new("warn")));
+    assert_eq!(env(&cmd, "RUST_LOG"), Some(OsStr::new("warn")));
+}
+
+#[test]
+fn from_env_var() {
+    assert!(Verbosity::<InfoLevel>::from_env_var("UNSET_LOG").is_none());
+
+    std::env::set_var("INVALID_LOG", "loud");
+    assert!(Verbosity::<InfoLevel>::from_env_var("INVALID_LOG").is_none());
+
+    for verbosity in [
+        Verbosity::<InfoLevel>::silent(),
+        Verbosity::new(0, 1),
+        Verbosity::new(2, 0),
+    ] {
+        let mut cmd = Command::new("child");
+        verbosity.write_env_for_subprocess(&mut cmd, "ROUND_TRIP_LOG");
+        std::env::set_var("ROUND_TRIP_LOG", env(&cmd, "ROUND_TRIP_LOG").unwrap());
+        let round_trip = Verbosity::<InfoLevel>::from_env_var("ROUND_TRIP_LOG").unwrap();
+        assert_eq!(round_trip, verbosity);
+    }
+}
+
+#[test]
+#[cfg(unix)]
+fn shell_export_string() {
+    let sh = |script: String| {
+        let output = Command::new("sh").args(["-c", &script]).output().unwrap();
+        assert!(output.status.success(), "{script}");
+        String::from_utf8(output.stdout).unwrap()
+    };
+
+    let verbosity = Verbosity::<InfoLevel>::new(0, 1);
+    let export = verbosity.shell_export_string(None);
+    assert_eq!(sh(format!("{export}; printf %s \"$RUST_LOG\"")), "warn");
+
+    let export = verbosity.shell_export_string(Some("MY_APP"));
+    let script = format!("{export}; printf '%s %s' \"$MY_APP_VERBOSE\" \"$MY_APP_QUIET\"");
+    assert_eq!(sh(script), "0 1");
+}
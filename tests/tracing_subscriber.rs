@@ -0,0 +1,12 @@
+#![cfg(feature = "tracing")]
+
+use clap_verbosity_flag::{InfoLevel, Verbosity};
+use tracing_subscriber::filter::LevelFilter;
+
+#[test]
+fn init_tracing_subscriber() {
+    let verbosity = Verbosity::<InfoLevel>::new(0, 1);
+    verbosity.init_tracing_subscriber();
+    assert_eq!(LevelFilter::current(), LevelFilter::WARN);
+    assert!(verbosity.try_init_tracing_subscriber().is_err());
+}